@@ -0,0 +1,63 @@
+//! Digits-only day 1 reading on a large generated document, with and
+//! without the byte-level fast path: `cargo bench --bench day01`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc2023::bench::{format_nanos, Stats};
+use aoc2023::days::day01::{self, Matcher, Recipe, Vocabulary};
+use aoc2023::table::Table;
+
+const ITERATIONS: usize = 20;
+
+fn main() {
+    let document = day01::generate(&Recipe {
+        seed: 2023,
+        lines: 200_000,
+        max_noise: 30,
+        ..Recipe::default()
+    })
+    .expect("the default noise has usable characters");
+    let lines: Vec<&str> = document.text.lines().collect();
+    let digits = Vocabulary::digits();
+
+    let mut table = Table::new(["matcher", "min", "median", "mean", "MB/s"]);
+    for (name, matcher) in [
+        ("trie", Matcher::trie(&digits)),
+        ("fast path", Matcher::new(&digits)),
+    ] {
+        let samples: Vec<Duration> = (0..ITERATIONS)
+            .map(|_| {
+                let start = Instant::now();
+                let total: u64 = lines
+                    .iter()
+                    .map(|line| {
+                        let first = matcher.first(black_box(line)).unwrap().value;
+                        let last = matcher.last(black_box(line)).unwrap().value;
+                        (first * 10 + last) as u64
+                    })
+                    .sum();
+                let elapsed = start.elapsed();
+                assert_eq!(document.digits_only, total as u128);
+                elapsed
+            })
+            .collect();
+
+        let stats = Stats::from_samples(&samples);
+        let throughput = document.text.len() as f64 / stats.median_ns as f64 * 1e3;
+        table.row([
+            name.to_owned(),
+            format_nanos(stats.min_ns as f64),
+            format_nanos(stats.median_ns as f64),
+            format_nanos(stats.mean_ns),
+            format!("{:.0}", throughput),
+        ]);
+    }
+
+    println!(
+        "{} lines, {} bytes\n\n{}",
+        lines.len(),
+        document.text.len(),
+        table
+    );
+}
@@ -0,0 +1,30 @@
+//! Day 1 must cope with any bytes at all: `cargo fuzz run day01_calibration`.
+#![no_main]
+
+use aoc2023::days::day01::{self, CalibrationMode, Extraction, MissingDigits, Options};
+use aoc2023::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    for mode in [CalibrationMode::DigitsOnly, CalibrationMode::DigitsAndWords] {
+        for extraction in [Extraction::FirstLast, Extraction::Concatenated, Extraction::LastK(3)] {
+            let options = Options {
+                extraction,
+                missing: MissingDigits::Skip,
+                threads: 2,
+                ..Options::new(mode)
+            };
+            let _ = day01::solve_reader(data, &options);
+        }
+        let _ = day01::solve_reader(data, &Options::new(mode));
+    }
+
+    let text = String::from_utf8_lossy(data);
+    if let Ok(lines) = day01::Day01::parse(&text) {
+        let _ = day01::Day01::part_one(&lines);
+        let _ = day01::Day01::part_two(&lines);
+        for report in day01::explain(&lines, CalibrationMode::DigitsAndWords) {
+            let _ = report.highlight(day01::Highlight::Brackets);
+        }
+    }
+});
@@ -0,0 +1,273 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::err::{describe, InputError, ParseError, SolutionError};
+use crate::input::Source;
+use crate::registry;
+use crate::solution::Part;
+use crate::table::Table;
+
+/// Answers we've already recorded, so later changes can be checked
+/// against them. Stored as TOML:
+///
+/// ```toml
+/// [[answer]]
+/// day = 5
+/// part = 1
+/// input = "fixtures/day-05.txt"
+/// expected = "579439039"
+/// ```
+///
+/// Input paths are relative to the file they're recorded in.
+#[derive(Debug, Default, Deserialize)]
+pub struct AnswerBook {
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<RecordedAnswer>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub expected: String,
+}
+
+impl AnswerBook {
+    pub fn load(path: &Path) -> Result<Self, SolutionError> {
+        let text = fs::read_to_string(path).map_err(|e| InputError::from_io(path.into(), e))?;
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, SolutionError> {
+        let book: Self = toml::from_str(text).map_err(|e| {
+            let snippet = e.span().map_or("", |span| &text[span.clone()]);
+            let mut err = ParseError::new("Malformed answers file", snippet);
+            if let Some(span) = e.span() {
+                let line = text[..span.start].matches('\n').count() + 1;
+                err = err.at_line(line);
+            }
+            err.caused_by(e)
+        })?;
+
+        if let Some(bad) = book
+            .answers
+            .iter()
+            .find(|a| Part::from_number(a.part).is_none())
+        {
+            return Err(
+                ParseError::new("Answer recorded for unknown part", bad.part.to_string())
+                    .on_day(bad.day)
+                    .into(),
+            );
+        }
+
+        Ok(book)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch(String),
+    Failed(String),
+}
+
+/// The outcome of re-solving one recorded answer.
+#[derive(Debug)]
+pub struct Check {
+    pub answer: RecordedAnswer,
+    pub verdict: Verdict,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.verdict == Verdict::Match
+    }
+}
+
+/// Re-solve every recorded answer, resolving input paths against `base`.
+/// Each input is parsed once no matter how many parts are recorded for it.
+pub fn verify(book: &AnswerBook, base: &Path) -> Vec<Check> {
+    let mut groups: Vec<(u8, &Path, Vec<&RecordedAnswer>)> = vec![];
+    for answer in book.answers.iter() {
+        match groups
+            .iter_mut()
+            .find(|(day, input, _)| *day == answer.day && *input == answer.input)
+        {
+            Some((_, _, answers)) => answers.push(answer),
+            None => groups.push((answer.day, &answer.input, vec![answer])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .flat_map(|(day, input, answers)| verify_group(day, &base.join(input), answers))
+        .collect()
+}
+
+fn verify_group(day: u8, input: &Path, answers: Vec<&RecordedAnswer>) -> Vec<Check> {
+    match Source::File(input.to_path_buf()).read() {
+        Ok(text) => check(day, &text, answers),
+        Err(e) => failed(answers, describe(&e)),
+    }
+}
+
+/// Solve `text` as the input for `day` and compare the result against
+/// each recorded answer.
+pub fn check(day: u8, text: &str, answers: Vec<&RecordedAnswer>) -> Vec<Check> {
+    let Some(entry) = registry::lookup(day) else {
+        return failed(answers, describe(&SolutionError::UnknownDay(day)));
+    };
+    let parsed = match entry.parse(text) {
+        Ok(parsed) => parsed,
+        Err(e) => return failed(answers, describe(&e)),
+    };
+
+    answers
+        .into_iter()
+        .map(|answer| {
+            let part = Part::from_number(answer.part).expect("parts are checked on load");
+            let verdict = match parsed.solve(part) {
+                Ok(actual) if actual == answer.expected => Verdict::Match,
+                Ok(actual) => Verdict::Mismatch(actual),
+                Err(e) => Verdict::Failed(describe(&e)),
+            };
+            Check {
+                answer: answer.clone(),
+                verdict,
+            }
+        })
+        .collect()
+}
+
+fn failed(answers: Vec<&RecordedAnswer>, reason: String) -> Vec<Check> {
+    answers
+        .into_iter()
+        .map(|answer| Check {
+            answer: answer.clone(),
+            verdict: Verdict::Failed(reason.clone()),
+        })
+        .collect()
+}
+
+/// A table of every check followed by a diff of each one that didn't match.
+pub fn summary(checks: &[Check]) -> String {
+    let mut table = Table::new(["day", "part", "input", "expected", "result"]);
+    let mut diffs = String::new();
+    for check in checks {
+        let answer = &check.answer;
+        let result = match &check.verdict {
+            Verdict::Match => "ok".to_owned(),
+            Verdict::Mismatch(actual) => {
+                let _ = writeln!(
+                    diffs,
+                    "--- day {} part {} ({})\n- {}\n+ {}",
+                    answer.day,
+                    answer.part,
+                    answer.input.display(),
+                    answer.expected,
+                    actual
+                );
+                "MISMATCH".to_owned()
+            }
+            Verdict::Failed(reason) => format!("FAILED: {}", reason),
+        };
+        table.row([
+            answer.day.to_string(),
+            answer.part.to_string(),
+            answer.input.display().to_string(),
+            answer.expected.clone(),
+            result,
+        ]);
+    }
+
+    let passed = checks.iter().filter(|c| c.passed()).count();
+    let mut summary = table.to_string();
+    if !diffs.is_empty() {
+        summary.push('\n');
+        summary.push_str(&diffs);
+    }
+    let _ = writeln!(summary, "\n{} of {} answers verified", passed, checks.len());
+    summary
+}
+
+#[test]
+fn reports_matches_and_mismatches() {
+    let book = AnswerBook::parse(
+        r#"
+[[answer]]
+day = 6
+part = 1
+input = "fixtures/day-06.txt"
+expected = "138915"
+
+[[answer]]
+day = 6
+part = 2
+input = "fixtures/day-06.txt"
+expected = "1"
+
+[[answer]]
+day = 6
+part = 1
+input = "fixtures/missing.txt"
+expected = "1"
+"#,
+    )
+    .unwrap();
+
+    let checks = verify(&book, Path::new(env!("CARGO_MANIFEST_DIR")));
+
+    assert_eq!(Verdict::Match, checks[0].verdict);
+    assert!(matches!(checks[1].verdict, Verdict::Failed(_)));
+    assert!(matches!(checks[2].verdict, Verdict::Failed(_)));
+}
+
+#[test]
+fn mismatches_are_diffed() {
+    let book = AnswerBook::parse(
+        r#"
+[[answer]]
+day = 6
+part = 1
+input = "fixtures/day-06.txt"
+expected = "138916"
+"#,
+    )
+    .unwrap();
+
+    let checks = verify(&book, Path::new(env!("CARGO_MANIFEST_DIR")));
+
+    assert_eq!(Verdict::Mismatch("138915".to_owned()), checks[0].verdict);
+    assert!(summary(&checks).contains("- 138916\n+ 138915\n"));
+}
+
+#[test]
+fn unknown_parts_are_rejected() {
+    let err = AnswerBook::parse(
+        r#"
+[[answer]]
+day = 6
+part = 3
+input = "fixtures/day-06.txt"
+expected = "1"
+"#,
+    )
+    .unwrap_err();
+
+    assert!(matches!(err, SolutionError::ParseError(_)));
+}
+
+#[test]
+fn malformed_entries_are_reported_on_their_line() {
+    let err = AnswerBook::parse("[[answer]]\nday = 6\nday = 7\n").unwrap_err();
+
+    match err {
+        SolutionError::ParseError(e) => assert_eq!(Some(3), e.line()),
+        e => panic!("unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,206 @@
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::err::SolutionError;
+use crate::output::{csv_row, Format};
+use crate::registry::Entry;
+use crate::solution::Part;
+use crate::table::Table;
+
+/// Wall-clock timings for each phase of one day, over repeated runs.
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub day: u8,
+    pub iterations: usize,
+    pub phases: Vec<PhaseTiming>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PhaseTiming {
+    /// `parse`, `part1` or `part2`.
+    pub phase: String,
+    pub stats: Stats,
+}
+
+/// Summary statistics over a set of samples, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Stats {
+    pub min_ns: u128,
+    pub median_ns: u128,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarise");
+
+        let mut nanos: Vec<u128> = samples.iter().map(Duration::as_nanos).collect();
+        nanos.sort_unstable();
+
+        let mid = nanos.len() / 2;
+        let median_ns = if nanos.len().is_multiple_of(2) {
+            (nanos[mid - 1] + nanos[mid]) / 2
+        } else {
+            nanos[mid]
+        };
+        let mean_ns = nanos.iter().map(|&n| n as f64).sum::<f64>() / nanos.len() as f64;
+        let variance = nanos
+            .iter()
+            .map(|&n| (n as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / nanos.len() as f64;
+
+        Self {
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+/// Time parsing and each solved part of a day `iterations` times over.
+///
+/// Parts are timed against a single parsed input, so their timings
+/// don't include parsing. Parts without a solution yet are left out.
+pub fn bench(entry: &Entry, input: &str, iterations: usize) -> Result<BenchReport, SolutionError> {
+    let iterations = iterations.max(1);
+
+    let mut parse_samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = entry.parse(input)?;
+        parse_samples.push(start.elapsed());
+        drop(parsed);
+    }
+
+    let mut phases = vec![PhaseTiming {
+        phase: "parse".to_owned(),
+        stats: Stats::from_samples(&parse_samples),
+    }];
+
+    let parsed = entry.parse(input)?;
+    for part in Part::ALL {
+        let mut samples = Vec::with_capacity(iterations);
+        for _ in 0..iterations {
+            let start = Instant::now();
+            let answer = parsed.solve(part);
+            samples.push(start.elapsed());
+            match answer {
+                Ok(_) => (),
+                Err(SolutionError::Unsolved) => break,
+                Err(e) => return Err(e),
+            }
+        }
+        if samples.len() == iterations {
+            phases.push(PhaseTiming {
+                phase: format!("part{}", part),
+                stats: Stats::from_samples(&samples),
+            });
+        }
+    }
+
+    Ok(BenchReport {
+        day: entry.day,
+        iterations,
+        phases,
+    })
+}
+
+impl BenchReport {
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Plain => self.table().to_string(),
+            Format::Json => {
+                let mut json =
+                    serde_json::to_string_pretty(self).expect("bench reports always serialise");
+                json.push('\n');
+                json
+            }
+            Format::Csv => {
+                let mut csv = String::new();
+                csv_row(
+                    &mut csv,
+                    [
+                        "day",
+                        "phase",
+                        "min_ns",
+                        "median_ns",
+                        "mean_ns",
+                        "stddev_ns",
+                    ],
+                );
+                for timing in self.phases.iter() {
+                    let stats = &timing.stats;
+                    csv_row(
+                        &mut csv,
+                        [
+                            &self.day.to_string(),
+                            timing.phase.as_str(),
+                            &stats.min_ns.to_string(),
+                            &stats.median_ns.to_string(),
+                            &stats.mean_ns.to_string(),
+                            &stats.stddev_ns.to_string(),
+                        ],
+                    );
+                }
+                csv
+            }
+        }
+    }
+
+    pub fn table(&self) -> Table {
+        let mut table = Table::new(["day", "phase", "min", "median", "mean", "stddev"]);
+        for timing in self.phases.iter() {
+            let stats = &timing.stats;
+            table.row([
+                self.day.to_string(),
+                timing.phase.clone(),
+                format_nanos(stats.min_ns as f64),
+                format_nanos(stats.median_ns as f64),
+                format_nanos(stats.mean_ns),
+                format_nanos(stats.stddev_ns),
+            ]);
+        }
+        table
+    }
+}
+
+/// A duration in the most readable unit, e.g. `12.34ms`.
+pub fn format_nanos(nanos: f64) -> String {
+    if nanos >= 1e9 {
+        format!("{:.2}s", nanos / 1e9)
+    } else if nanos >= 1e6 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else if nanos >= 1e3 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else {
+        format!("{:.0}ns", nanos)
+    }
+}
+
+#[test]
+fn stats_summarise_samples() {
+    let samples: Vec<Duration> = [4, 1, 3, 2].into_iter().map(Duration::from_nanos).collect();
+
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(1, stats.min_ns);
+    assert_eq!(2, stats.median_ns);
+    assert_eq!(2.5, stats.mean_ns);
+    assert!((stats.stddev_ns - 1.118).abs() < 1e-3);
+}
+
+#[test]
+fn times_each_solved_phase() {
+    let entry = crate::registry::lookup(6).unwrap();
+
+    let report = bench(entry, "Time: 7 15 30\nDistance: 9 40 200\n", 3).unwrap();
+
+    let phases: Vec<&str> = report.phases.iter().map(|p| p.phase.as_str()).collect();
+    assert_eq!(vec!["parse", "part1"], phases);
+    assert_eq!(3, report.iterations);
+}
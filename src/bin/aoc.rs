@@ -0,0 +1,422 @@
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use aoc2023::answers::{self, AnswerBook};
+use aoc2023::bench;
+use aoc2023::days::day01::{
+    self, CalibrationMode, Day01, Extraction, Highlight, MissingDigits, Options, Recipe,
+};
+use aoc2023::days::day02::{self, Day02, Query};
+use aoc2023::err::{report, SolutionError};
+use aoc2023::input::{self, Source};
+use aoc2023::output::{self, Format};
+use aoc2023::registry::{self, Entry, REGISTRY};
+use aoc2023::runner::{self, DayRun};
+use aoc2023::scaffold;
+use aoc2023::solution::{Part, Solution};
+use aoc2023::trace::{self, Level};
+use clap::{Args, Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Explain what every day is doing on stderr. Repeat for more detail.
+    #[arg(long, short, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Trace one day in detail, e.g. `day05` or `day03=debug`. Repeatable.
+    #[arg(long, global = true, value_name = "TARGET[=LEVEL]")]
+    trace: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one day, or every registered day, and print the answers.
+    Run(RunArgs),
+    /// Re-solve every recorded answer and report any that changed.
+    Verify(VerifyArgs),
+    /// Time parsing and each part of a day over repeated runs.
+    Bench(BenchArgs),
+    /// Generate the module, registry entry, input and example for a new day.
+    New(NewArgs),
+    /// Show how each line of the input was read.
+    Explain(ExplainArgs),
+    /// Sum the day 1 calibration values of a file of any size, reading it
+    /// as a stream.
+    Calibrate(CalibrateArgs),
+    /// Write a synthetic day 1 calibration document to stdout, with its
+    /// answers on stderr.
+    Generate(GenerateArgs),
+    /// Ask a question of the day 2 cube games, e.g.
+    /// `games where max(red) > 15` or `sum(blue), count where draws <= 3`.
+    Query(QueryArgs),
+    /// Find the day 2 bag that supports the most games within a budget of
+    /// cubes, or the smallest bag that supports enough games.
+    Bag(BagArgs),
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Day to run.
+    #[arg(long, short, required_unless_present = "all")]
+    day: Option<u8>,
+
+    /// Run every registered day.
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    all: bool,
+
+    /// Only run this part (1 or 2). Runs both when omitted.
+    #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Puzzle input to use instead of `inputs/day-NN.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    /// How to print the answers: plain, json or csv.
+    #[arg(long, short, default_value = "plain")]
+    format: Format,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+    /// File of recorded answers.
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+    /// Day to benchmark.
+    #[arg(long, short)]
+    day: u8,
+
+    /// Puzzle input to use instead of `inputs/day-NN.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    /// How many times to run each phase.
+    #[arg(long, short = 'n', default_value_t = 100)]
+    iterations: usize,
+
+    /// How to print the timings: plain, json or csv.
+    #[arg(long, short, default_value = "plain")]
+    format: Format,
+}
+
+#[derive(Debug, Args)]
+struct NewArgs {
+    /// Day to create.
+    #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Root of the crate to add the day to.
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct ExplainArgs {
+    /// Day to explain. Only day 1 can be explained so far.
+    #[arg(long, short, value_parser = clap::value_parser!(u8).range(1..=1))]
+    day: u8,
+
+    /// Explain this part's reading of the input (1 or 2).
+    #[arg(long, short, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Puzzle input to use instead of `inputs/day-NN.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    /// Mark the digits found in each line.
+    #[arg(long)]
+    highlight: bool,
+}
+
+#[derive(Debug, Args)]
+struct CalibrateArgs {
+    /// Read the digits the way this part does (1 or 2).
+    #[arg(long, short, default_value_t = 2, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// File to read instead of `inputs/day-01.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+
+    /// Which digits make up a line's value: first-last, all, first:K,
+    /// last:K or sum.
+    #[arg(long, short, default_value = "first-last")]
+    extract: Extraction,
+
+    /// What to do with lines that have no digits: skip, zero or abort.
+    #[arg(long, short, default_value = "abort")]
+    missing: MissingDigits,
+
+    /// Worker threads to spread the lines over.
+    #[arg(long, short, default_value_t = 1)]
+    threads: usize,
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+    /// Seed for the document; the same seed gives the same document.
+    #[arg(long, short, default_value_t = 0)]
+    seed: u64,
+
+    /// Number of lines.
+    #[arg(long, short, default_value_t = 1000)]
+    lines: usize,
+
+    /// Filler characters to put between digits.
+    #[arg(long, default_value = "abcdefghijklmnopqrstuvwxyz")]
+    noise: String,
+
+    /// Longest run of filler between digits.
+    #[arg(long, default_value_t = 6)]
+    max_noise: usize,
+
+    /// Most digits on one line.
+    #[arg(long, default_value_t = 5)]
+    max_digits: usize,
+
+    /// Share of digits spelled out as words, from 0 to 1.
+    #[arg(long, default_value_t = 0.5)]
+    word_ratio: f64,
+
+    /// Share of words that overlap the next one, from 0 to 1.
+    #[arg(long, default_value_t = 0.2)]
+    overlap_ratio: f64,
+}
+
+#[derive(Debug, Args)]
+struct QueryArgs {
+    /// `games` or a list of aggregates (count, sum, min, max), optionally
+    /// followed by `where` and a condition.
+    query: String,
+
+    /// File to read instead of `inputs/day-02.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+}
+
+#[derive(Debug, Args)]
+#[group(required = true, multiple = false)]
+struct BagGoal {
+    /// Most cubes the bag may hold, of every colour together.
+    #[arg(long, short)]
+    budget: Option<usize>,
+
+    /// Fewest games the bag must support.
+    #[arg(long, short)]
+    games: Option<usize>,
+}
+
+#[derive(Debug, Args)]
+struct BagArgs {
+    #[command(flatten)]
+    goal: BagGoal,
+
+    /// File to read instead of `inputs/day-02.txt`, or `-` for stdin.
+    #[arg(long, short)]
+    input: Option<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let verbosity = match cli.verbose {
+        0 => None,
+        1 => Some(Level::Info),
+        2 => Some(Level::Debug),
+        _ => Some(Level::Trace),
+    };
+    let config = cli.trace.iter().try_fold(
+        trace::Config {
+            default: verbosity,
+            targets: vec![],
+        },
+        |config, spec| config.with_spec(spec),
+    );
+    match config {
+        Ok(config) => trace::configure(config),
+        Err(e) => return report(Err(e)),
+    }
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => report(bench(args)),
+        Command::New(args) => report(new_day(args)),
+        Command::Explain(args) => report(explain(args)),
+        Command::Calibrate(args) => report(calibrate(args)),
+        Command::Generate(args) => report(generate(args)),
+        Command::Query(args) => report(query(args)),
+        Command::Bag(args) => report(bag(args)),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let parts = match args.part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+
+    let entries: Vec<&Entry> = match args.day {
+        Some(day) => match registry::lookup(day) {
+            Some(entry) => vec![entry],
+            None => return report(Err(SolutionError::UnknownDay(day))),
+        },
+        None => REGISTRY.iter().collect(),
+    };
+
+    let runs: Vec<DayRun> = entries
+        .into_iter()
+        .map(
+            |entry| match input::load(entry.day, args.input.as_deref()) {
+                Ok(text) => runner::run(entry, &text, &parts),
+                Err(e) => DayRun::unloaded(entry.day, e.in_day(entry.day)),
+            },
+        )
+        .collect();
+
+    print!("{}", output::render(&runs, args.format));
+
+    if runs.iter().any(DayRun::failed) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let book = match AnswerBook::load(&args.answers) {
+        Ok(book) => book,
+        Err(e) => return report(Err(e)),
+    };
+    let base = args.answers.parent().unwrap_or(Path::new(""));
+
+    let checks = answers::verify(&book, base);
+    print!("{}", answers::summary(&checks));
+
+    if checks.iter().all(|c| c.passed()) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), SolutionError> {
+    let entry = registry::lookup(args.day).ok_or(SolutionError::UnknownDay(args.day))?;
+    let text = input::load(entry.day, args.input.as_deref())?;
+
+    let report = bench::bench(entry, &text, args.iterations)?;
+    print!("{}", report.render(args.format));
+
+    Ok(())
+}
+
+fn new_day(args: NewArgs) -> Result<(), SolutionError> {
+    if registry::lookup(args.day).is_some() {
+        return Err(SolutionError::Io(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("day {} is already registered", args.day),
+        )));
+    }
+
+    for path in scaffold::generate(&args.root, args.day)? {
+        println!("wrote {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn explain(args: ExplainArgs) -> Result<(), SolutionError> {
+    let text = input::load(args.day, args.input.as_deref())?;
+    let lines = Day01::parse(&text)?;
+    let highlight = args.highlight.then(|| {
+        if std::io::stdout().is_terminal() {
+            Highlight::Ansi
+        } else {
+            Highlight::Brackets
+        }
+    });
+    print!(
+        "{}",
+        day01::report_table(
+            &day01::explain(&lines, calibration_mode(args.part)),
+            highlight
+        )
+    );
+
+    Ok(())
+}
+
+fn calibrate(args: CalibrateArgs) -> Result<(), SolutionError> {
+    let reader = Source::resolve(Day01::DAY, args.input.as_deref()).open()?;
+    let options = Options {
+        extraction: args.extract,
+        missing: args.missing,
+        threads: args.threads,
+        ..Options::new(calibration_mode(args.part))
+    };
+    let calibration = day01::solve_reader(reader, &options)?;
+    println!("{}", calibration.total);
+    if calibration.without_digits > 0 {
+        eprintln!("{}", calibration);
+    }
+
+    Ok(())
+}
+
+fn generate(args: GenerateArgs) -> Result<(), SolutionError> {
+    let document = day01::generate(&Recipe {
+        seed: args.seed,
+        lines: args.lines,
+        noise: args.noise,
+        max_noise: args.max_noise,
+        max_digits: args.max_digits,
+        word_ratio: args.word_ratio,
+        overlap_ratio: args.overlap_ratio,
+    })?;
+
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(document.text.as_bytes())?;
+    eprintln!("part 1: {}", document.digits_only);
+    eprintln!("part 2: {}", document.digits_and_words);
+
+    Ok(())
+}
+
+fn query(args: QueryArgs) -> Result<(), SolutionError> {
+    let text = input::load(Day02::DAY, args.input.as_deref())?;
+    let games = Day02::parse(&text)?;
+    let query = Query::parse(&args.query, &games).map_err(SolutionError::Query)?;
+    print!("{}", query.run(&games));
+
+    Ok(())
+}
+
+fn bag(args: BagArgs) -> Result<(), SolutionError> {
+    let text = input::load(Day02::DAY, args.input.as_deref())?;
+    let games = Day02::parse(&text)?;
+    let fit = match (args.goal.budget, args.goal.games) {
+        (Some(budget), _) => day02::best_bag(&games, budget),
+        (None, Some(k)) => day02::smallest_bag(&games, k).ok_or(SolutionError::NoAnswer)?,
+        (None, None) => unreachable!("clap requires a budget or a number of games"),
+    };
+    println!("{}", fit);
+
+    Ok(())
+}
+
+fn calibration_mode(part: u8) -> CalibrationMode {
+    match part {
+        1 => CalibrationMode::DigitsOnly,
+        _ => CalibrationMode::DigitsAndWords,
+    }
+}
@@ -1,307 +1,10 @@
-use std::{collections::HashSet, io};
+use std::process::ExitCode;
 
-use aoc2023::err::SolutionError;
-use regex::Regex;
+use aoc2023::days::day04::Day04;
+use aoc2023::err::report;
+use aoc2023::input;
+use aoc2023::solution::{print_answers, Solution};
 
-fn main() -> io::Result<()> {
-    println!("part 1: {}", part_one(INPUT).expect("FAILED PART ONE"));
-
-    Ok(())
-}
-
-fn part_one(input: &str) -> Result<usize, SolutionError> {
-    let parser = CardParser::new();
-    input
-        .lines()
-        .filter(|s| !s.is_empty())
-        .try_fold(0, |sum, s| parser.parse(s).map(|c| sum + c.score()))
-}
-
-type NumberSet<'a> = HashSet<&'a str>;
-
-struct Card<'a> {
-    // id: &'a str,
-    mine: NumberSet<'a>,
-    winning: NumberSet<'a>,
-}
-
-impl<'a> Card<'a> {
-    fn score(&self) -> usize {
-        let matches = self.mine.intersection(&self.winning).count();
-        if matches == 0 {
-            0
-        } else {
-            1 << (matches - 1)
-        }
-    }
-}
-
-struct CardParser {
-    parts_parser: Regex,
-    number_parser: Regex,
-}
-
-impl CardParser {
-    fn new() -> Self {
-        Self {
-            parts_parser: Regex::new(r"^Card +(?<id>\d+): (?<winning>[^|]+)\|(?<mine>.*)$").unwrap(),
-            number_parser: Regex::new(r"\d+").unwrap(),
-        }
-    }
-
-    fn parse<'a>(&self, s: &'a str) -> Result<Card<'a>, SolutionError> {
-        let (_, [_id, winning_str, mine_str]) = self
-            .parts_parser
-            .captures(s)
-            .ok_or_else(|| {
-                SolutionError::ParseError("Input string not shaped like a card", s.to_owned())
-            })?
-            .extract();
-        let winning = self
-            .number_parser
-            .find_iter(winning_str)
-            .map(|m| m.as_str());
-        let mine = self.number_parser.find_iter(mine_str).map(|m| m.as_str());
-
-        Ok(Card {
-            // id,
-            winning: winning.collect(),
-            mine: mine.collect(),
-        })
-    }
+fn main() -> ExitCode {
+    report(input::load_from_args(Day04::DAY).and_then(|text| print_answers::<Day04>(&text)))
 }
-
-#[test]
-fn example_one() {
-    let input = r#"
-Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83 
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
-"#;
-
-    assert_eq!(13, part_one(input).unwrap());
-}
-
-const INPUT: &'static str = r#"
-Card   1: 79 93 21 74 81 76 17 89  3  5 |  5 67 87 81 76 35 79 21 15 80  8 74 99 28  3 23 19 42 89 16 22 77 92 70 34
-Card   2: 83 16 24 23 59 70 14 57 74 53 | 79 82 70 23 61 14 74 57 36 37 59 72 83 16  3  2 28 63 50 60 38 86 97 24 53
-Card   3: 12 77 13 14 48 55 69  4 18 81 | 69  7 94 88 18 73 55 48 49 81 14 21 12 15  5 27 22 84 51 52 13 77  4 57 17
-Card   4: 32 35 57 27 15  5 16 40 36 46 | 84 47 76 35 83  5 22 15 72 48 57 33 46 53 43 16 10 65 32 51 36 44 40 27 97
-Card   5:  8 72 57 36 45 96  7 13 17 14 | 46 40  8  7 57 60 69 89 17 13 16 51 45 72 55 64 76 42 14 29 21  9 96 36 94
-Card   6: 22 46 55  8 70 91 11 89 31  5 | 31 22 40 10 11 61 42 19 87 92 96 34 76 21 89 18 46  8 55 30 36 70 91 63  5
-Card   7:  5 66 19 57 97 24 45 44 29 86 | 27  1 70 97 60 90 93 69 29 44 86 49 42 57 58 45 88 24  5 19 95 66 46 78 31
-Card   8: 67  4 51 50 91 39 63 52 77 47 | 61 23 54 77 63 51 98 20 47 57 90  7 91 65 79 82 39 72 75 24 52 27 50 67  4
-Card   9: 99 26  2 15 51 56 63 69 39 41 | 91 10 81 53 23 41 75 33  3 39  9 24  2  5 93 51 25 37 99 69 15  7 35 67 63
-Card  10: 31 90 84 42 34 74 96 66 71 75 |  4 31 43 90 96 70 60 75  5 42 11  6 21 76  2 41 34 99 84 14 77 33 24 71 12
-Card  11: 66 55 30 68 45  5 82 71 27 85 | 60 26 40  3 94 93  2 76  7 75 46 47 82 37  8 34 62 92 51 53 32 88 12 44 83
-Card  12: 49 39 86 99 88  3 51 41 46 64 | 31 95 25 32 19 98  3 78 88 94 50 80  4 51 55 35 17 24 89 71 67 99  2 69 37
-Card  13: 53 56 84  7 35  8 55 75 24 13 | 42 34 30 94 81 95 12 68 69 38 50 17 11 23 89 19 15 79 47 26 88 99 16 44 27
-Card  14: 42 88 47 86 61 36 20 59 83 99 | 95 93 88  7 13 34 39 97  8 99 22 86 30 64 15  2 41 12 65 84 61 21 57 35 78
-Card  15: 59 90 32  6 57 42  1 46 34 23 | 28 59 62 16 64  3 13 14 25 46 29 75 92 23 84 82 98 55 73 38 79 19 53 22 80
-Card  16: 57  2 10 43 54 75 87 76  6 95 | 55 89  7 70 51 85 99 49 48 36 54 58  4 76 52 93 19 82 74 66 34 64 63 39 33
-Card  17: 63  3 96 69 20 41 18 25 55 90 | 75 96 11 64 38 53 34 55 26 18 50 78 44 13 98 91 10 47 92 99 67 20 54 83 17
-Card  18:  2 67 70 62 30 41 99 92 47  6 | 47 37 26 61 50 78  5 69 63 55 59 62 89 84 22  3 10 85 40 91 66 82 51 46 52
-Card  19: 75 88 99 27 41 95 26 40 82 17 | 79 68 98 89 33 86 73 32 84 40 62 44 31 14 61 48 42 29 10 21 64 92 49 59 60
-Card  20: 87  7 76 82 93 59 69 64 89 90 |  1 82 66 75 70 81 51 61 87 86 39 91 64 32 93 44  5 78 34 92 26 50 18 58 85
-Card  21:  4  9  3  5 16 29 32 41 82 61 | 90 63 39  8 99 73 43 11 17 98 88 80 62 25 76 95 31 75  6 38 52 55 18 53 89
-Card  22: 47  6 22  1 92 56 33 32 55 41 | 52 98 94 99 90 55 17 62 91  4 70 86  1 80 44 57 31 85 64 76 45 27 24 95 28
-Card  23: 59 95 56 20 97  5 52  9 65  1 | 80 99 46 19 24 57 44 78 25 93  8 73 45 94 48 42 89 41 63 54 81 10 17  2 90
-Card  24: 10 56 60 73 67 92 76 77 74 21 | 48 18 82  7 85 65 89 98 58 57 19 49 53 87 25 88 51 15 72 79 17 44 91 66 39
-Card  25: 43 34 14 37 96 24 64 77 88 93 | 45 22 96 99 10 14 51 11 26 67  1 76 81  5 79 12 93 60 73 56 52 25 30  7 24
-Card  26:  8 64 27 73 95 78 99 23 68 77 |  5 23 70 12 96 18 38 37 11 67 76 91 64 22 77  7  6 99  8  1 60 17 26 73 95
-Card  27: 16 43 63 89 26 40 85 56 24 77 | 87 85 70  1  8 62 24 27 40 46 42 21 50 94 56 91 64 66 11 71 74  3 60 97 12
-Card  28: 52 94  4  5 25 20 16 88 99 71 | 67 68 99 25  4 72 16 87  5 64 62 78 71  8 88 65 20 95 47 73 55  9 94 41 52
-Card  29: 81 52 45 80 96  2 77 24 67 82 | 30 13 53 60  1 21 50 74 33 91 80 59 92 58 66 63 89 68 70 47 43  4 95 18  9
-Card  30: 43 36 55 32 65 19  3 86 95 48 |  2 99 95 56 82 25  7 44 71 43 12 79 70  8 65 83 46 63 55 60 52  4 61 91 81
-Card  31: 61 14 29 96 87 34 64 33 62 18 | 14  7 74 27 86 50 28 89 84 82 51 34 72  8 33 29 68 45 31 71 93 64 96 62 75
-Card  32:  4 92 88 97 52 16 87 13 39 91 | 52 57 29 32  4 88 54 37 15 42 97 28 39 20 92 85 46 99 38 48 34 41 79 13 36
-Card  33:  1  8 74 33  3 10 94 81 53 38 | 30 98  6  3 58 34 53 99 31 21 77 33 71 45 82 95 94 81 62 97 38 89 35 78  8
-Card  34: 59 16  9 51 22 20 47 90 38 74 | 79 58 68 71 96 91 76 46 63 34 16 19 57 83 14 38 60 75 20  3  7 73 12 24 40
-Card  35: 76 85 88 78 79 11 72  8 74 63 | 97 35 18 34 17 24 10 68  7 43 14 77 74 59 94 29 61  9 46 81 71 52 98 78 40
-Card  36: 17 56 10 34 38 59 80 94 45 18 | 73 21 15 30 91 48 31 76 19 77 26 43 80 87 55  6 41 54 59 52 72 38 40  5 37
-Card  37: 33  4 82 53 95  8 87 45 42 29 | 35 21 36 89 76 38 44 96 78 25 15 87 45  3 55 51 27 82 37 79 75 73 12 56 40
-Card  38: 96 86 71 24 30 14 38 99 44 56 |  3 32 70 24 42 37 36 78 79 55 93 92 94 41 96 76 25 27 98 20 75 60 62 22 67
-Card  39: 30 88 10 54 62 44 91 75 72 29 | 77 73  6 60 70 49 17 23  3 76  7 59 40 45 43  8 52 36 92 95 78 48 26 18 34
-Card  40: 46 56 10 17 37 55 97  7 80 38 | 18 88 32 49 65 22 61  8 85 34 87 16 67 94 51 30 64 57 15 74 50 25 91 27 73
-Card  41: 97 89 15 84 48 98 91 33  6  5 | 38  5 97 91 89 96 36 41 24 48 26  4 64 16 40 98 84 75 15  6 33 27 51 53 57
-Card  42: 71 10 32 84 41 20 59 91 79 63 |  9 12 70 67 17 10 98 80 40 47 42 22 36  4 25  2 57 78 39 15 49 23 31 14 55
-Card  43: 97 12 53 59 36 13 69 87 49 95 | 57 97 90 29 93 49 87 60 13 39 53 12 92 11 26 59 17 47 78  1 69 95 70 36 14
-Card  44: 16 32 83 30 89 53  5 44 27  2 |  5 53 78 60 18 83 40 30 32 64 97 24 27 11 29 39  2 16 33 89 19 75 44 72 20
-Card  45: 62 83 99 24 93  1 47 43  7 76 | 88 39 91 66 20 59 58 85 30 22 87 90 29 18 89 26 65 79 92 27 16  9  2 45  7
-Card  46: 33 74 24 58 36 10 52 77 86 15 | 50 36  8 51 42 90  1 29 39 55 43  4 94 49 66 59 91 30 44 83 98 54 48 74 10
-Card  47: 42 95 17 88 16 36 77  1  8 76 | 42 77 36  8 34 76 92 17 75  1 16 48 73 88 95 26 32 93 58 55 39 70 67 45  4
-Card  48: 55 89 87 90 38 44 39 94 53 61 | 32 26 41 25 57 22 40 27  5 73 61 65  4 94 38 44 39 13 87 67 69 55 93 74 48
-Card  49: 88 11 92 90 89 49 75 14 83 68 | 89 18 97 79  4 90 32 92 68 54 63 19 62 35  2 34 75 23 58 45 17 66 80 81 14
-Card  50: 21 24 41 30  6 47 14 46 25 91 | 76 20 47 55 79 64 32 53 54 68  1 81 22 70 42 33 40 80 73 28 99 71 25 90 16
-Card  51: 68 27 73 53 74 11 85 72 43 52 | 76 30 82 98 43 78  2 45 31 97  1 33 11 85 91 57 93  9 20 42 29 26 38 21 71
-Card  52: 82 37 29  1 44 34 17  2 56 46 |  3 29 86 42 38 56 17 84 33 64 34  2 78 44 82 46 10 22 99 54 79 62 19 28 37
-Card  53: 91 92  2 14 72 31 75 93 40 70 | 78 31 81 41 90 93 64 65 75 34 29  2 18 92 20  8 10 71 40 26 63 69 70 91 88
-Card  54: 13 70 80 98 49 43 54 40 79 81 | 75 49 51 52 88 83 98 89 30 20 26 56 81 74 70 40 46 35 12  7 99 43 24 31 79
-Card  55: 57  3 65 47 95 38 87 90 15 27 | 14 15 99 76 75 68  3 44  8 36 30 51 83 93 38 50 29 55 27 40 71 31 10 12 33
-Card  56: 45 22 78 37 35 89 46 90 58 47 | 67 40 73 64 17 21 91  6 84 18 63 78 60  1 16 71 25  2 68 43  8 35 22 28 33
-Card  57: 21 43 41 18 56 70 42  1  2 23 | 53 69 99 79 95 15 94 48 58 73 87 12 13 68 11 16 96 64 78 59 71 75 54  7 98
-Card  58: 42 70 71 74 14 91 68 76 61 90 | 32 59 77 10  2 48  5 57 67 99 13 70 25 84  8 61 24 92  6 47 94 72 28 31 73
-Card  59: 86 43 14 77 96 67 35 97  9 98 | 51 18 32 99 88 13 71 81 77 34 20 89 12  2 50 21 53 97  6 80 85 45 47 40 48
-Card  60:  1 57  2 96 35 98 16 40 86 12 | 41 22 36 21 75 67 92 54 65 85 61 78 48 27 58 63 52  3 79 37  4 93 31 90 47
-Card  61: 78 32 26 64 91  7 86 31  8 73 | 52 41 42 16 81 17  9 72 57 11 15 62 98 51 34 19 85 97 48 18 24 77 88 45 27
-Card  62: 59 47 95 38 13  6 23 32 58 52 |  4 39  3 55 66 84 86 42 77 75 41 96 36 65 45 99 89  8 74 88 31 29 19 82 97
-Card  63: 85  1 82  7 27 73 43  2 19 67 | 46 82  1 42  5 36 73 19 88 35 57 39 78 27 83 37 26  7 67 58 31 12  2 94 10
-Card  64: 23 74 35 40 57 44 71  5 83 42 | 40 57 97 90 12 74 47 91 19 49 83 71 42 92 80  5 60 54 44 23 14 86 82 16 35
-Card  65: 11 60 73  4 58 45 54 74  3 19 | 21 73  3 96 80 91 26 19 39 54 41 68 71  4 60 45 15 36 58 77 59 95 65 22 11
-Card  66: 45 78 24 28 60 23 81 30 57 46 |  7 46 53 23 99 30 79 76 43 78 11 40 60 54 47 36 28 45 17 57 69 65 24 92 81
-Card  67: 14  8 55 99 35 98 95 69 38 87 | 59 12 14 30 82 63 69 85 61 55 65 32 58 97 60 80 87 98  1 95  8 38 89 99 35
-Card  68: 78 89 74  2 32 39 14 41 66 49 | 57 71 82 76 51 16 97 41 58 20 36 22 15  2 39 43 14 29 66 89 32 42 49 56 65
-Card  69: 82 29 57 97 75 98 19 16 70 93 | 47 93 24 91 70 60 52 28 97 50 42 45 17 82 81 33 57 84 95  6 85 62 89 16 98
-Card  70: 99 92 75 80  6 13 59 68  5 23 |  7 78 94  4 15 96 26 57  1 58 20 43 29 73 46 87 67  9 25 24 32  3 93  2 81
-Card  71: 61 33 23 10 46 90 41 88 49 19 | 75 67 20 35 24 46  9 69 92 27 57 49 43 19 88 89 53 38 90 84 94 33 59 31  6
-Card  72: 57 43 96 12  8 78 37 68 28 17 | 76 46 60 97 31 77 21  5 73 32 82  2 61 50  6 28 39  3 85 29 71 94 38 93 79
-Card  73: 66 51 48 31 92 22 80 23  2 12 | 59 97 92 10 45 28 51 73 95 69 62 38 14 86 72 33  8 94 11  2 12 68 31  5 13
-Card  74: 88 32  3 74 60 16 69 80  9 34 | 82  3 72 90 27 36 88 38 16 14 80 46  5 18 32 69 41 34 45 61 62 60 52 74  9
-Card  75: 73 48 35 68 66 81 70  1 94 20 | 91 74  1 78 16 94 73 92 53 96 89 13  8 43 95 47 80 42 11 72 26 24  5  6 66
-Card  76: 87 14 23 58 92 89 69 76 49 88 | 66 43 87 84  8 83  5 78 23 44 58 48 61 49 98 40 82 10 69 88 35 73 20 27 50
-Card  77: 29 94 13 42 21 64 74 81 67 58 | 86 96 48 40 29 27 80 97 51 22 67 72 91 16 64 84 26 78 58 49 34 53 30 77 46
-Card  78: 90 49 51 57 52 89 33 26  1 29 | 77 23 34 56 96 72 44 14 97 76 51 64 33 91 90 80 74 69 52 55 27 83 93 60 82
-Card  79: 13 81 79 91 28  2 77 60 29 19 | 32 42 81 26  7 64 28 35 57 11 73 47 21  1 76 97 84 53 55 48 58 80 38 59 83
-Card  80: 88 20 72 86 53 82  4 31  1 66 |  3  6 17 47 15  7 88 11 90  4 70 58 31 91 72 82 94 97  1 10 50 25 55 65 84
-Card  81: 64 53 88 41 73 67 13 84 87 85 | 26  9 15 83 18 74 43 10 42 65 79 61 66  4 45 70 12  5 47 24 34 81 94 44  6
-Card  82: 89 85 86 35 81 98 92 77 57 96 | 31 35 48 97 53 85 57 79 19 10 14 49 98 66 34 33 87 18 27 36 26 24 76 56 84
-Card  83: 81 34 43 58 20 37 35 98  2  1 | 74 66 68  2 77 40  8 34 72 88 87 28 71 35 76 60 51 73 75 12 44 33 19 52 47
-Card  84: 79  3 72  7 98 71 49 96 28 54 | 41 75 46 92 14 69 87 70 33 29 31 73 86 10 54 34 71 47 80 45 38 68 93 27 88
-Card  85: 75 99 49 51 69 77 72 18 26 28 | 97 96  6 58 77 60 45 27  5 93 85 23 71 15 87 94 46 37 29  3 33 65 79 56 59
-Card  86: 98 43 83 76 91 64 34 89  4 77 | 60 12 42 70 26 30  2 63 85 74 84 38 49 39  1 96 72 50 24 71 52 65 32 29 44
-Card  87: 34 18 59 16 85 46 47 41 98 51 | 99 69 68 28 53 22 73 90  6 58 56 20 84  3 39  9  8 94 13 82 93 81  5 91 67
-Card  88:  6 65 71 21 90  3 82  5 56 81 | 44 97 47 76  3 26 29 90 21 57 46 33 81 19  5 38 30 65 78 79 71  9 43 80 48
-Card  89: 40 98 59 87 43 28 94 93 71 57 | 76 73 60 11 18 87 67 25 65 64 49 42 46 78 63 14 54 98 74 66  4 59 80 16 36
-Card  90: 49 36  9 94 82  3  6 10 54 90 | 55 99 45 77 65 76 56 13 22 19 53 69 81 23 18 71 68  5  4 15 62 98 12  7 59
-Card  91: 46 50 30 75 28 58 95 60 14 53 | 10 75 72 61 11 23 88 28 63  4 86 95 81 68 50 34 45 43 32 52 53 58 59 60 31
-Card  92:  5 85 10 63  6 46 66 34  1 65 | 43 35 22 61 30 44 27  4 75 80 89 55 47 90 77 59 76 21 70 36 51 56 40 69 57
-Card  93:  5  3 47 22  2 28 71 93 97 24 | 94 41 29 79 15 21 68 83 13 70 30 77 74 99 86  7 43 88 40 69 45 55 54 31 47
-Card  94:  6 98 60 25 58  2 20 97 36 39 | 10 29 22 39 48 14 96 88 86 50  5 99 84  2 12 89 83 60 75 36 20 46 15 13 35
-Card  95: 11 78 92 55 59 54 98 52  5  9 | 82 33 98  1 19 83 59 15 42 99 34 20 23 17 51 50 71 84 67 54 44 16  5 31 35
-Card  96: 37 14 40  1 98  8 80 42 69 16 | 58  4 61 16 49 46  1 42 36 98 19 80  2 68 29 43 13 93 88  8 45 96 79 35 77
-Card  97: 39  5 67  7 63 46  4 79 29 54 | 35 20 78 12 64 57 89  7 11 84 94 41 43 42 73 23 28 97  1 91 90 19 65 15 32
-Card  98: 69 14  6  7  1 37 85 38 55 95 | 32 58 25 38 11 81 33 42 21 96 84 53 97  7 50 16  1 28 98 78 18 79 95  8 54
-Card  99: 39 66 49 53 87 16 20  7 92 59 | 87 41 22 14 92 95 98 63 73 18  6 58 27 25 12 28 26 40 48  1 77 20 64  8 11
-Card 100: 15  1 30 34 27 66 98 71 94 49 | 41 48 99 84 59 30 16  3 60 40 78 86 73 42 70 76  9 69 25 54 81 21 67 18  5
-Card 101: 61 57 53 37 93 76 96 49 12 58 | 17 26 40 43 29  1 80 54 42 64 95 73 41 25 77 72 45 50 75 32 68 87 36 81 70
-Card 102: 94 57 80 25 90  7 52 51 78 21 | 50 66 23 42  2 81 20  3  4 49 98 30 45 33 64 47 86 75 62 27 79 17 69 87 46
-Card 103: 83 71 38 14 26 22 66 93 99  3 | 28 92 72 53 84 19 49 88 36 45 34 32 60 33 56 44 78 21 35 76 10  5 24 15 94
-Card 104: 98 53 74 12 75 30 14 63 29 47 | 28  2  3 16 96  5  6 19 68  8 90 91 95 14 25 36 67 38 64 50 35 78 47 24 87
-Card 105: 95 81 93 69  8 43 79 83 30 49 | 87 49 69 81 43 29 27 93 44 83  8 18 40 30 97 28 41 56 95 51 26 96 20 85 79
-Card 106: 39 36 97 77  7 60  4 24 83 92 | 50 38 14 52 92 74 24  7 23 13 39 77  3 97 40 93 83 73 15 60 44  9 34 36  4
-Card 107: 53 67 85 36 27 70 89 50 54 99 | 72 36 21 27 31 44 71 99 10 85 50 40 54 34  3 91 89 98 70 82 67 41 18 43 53
-Card 108: 29 70 45 18 90 58 77 48 82 62 | 28 17 26  9 12 64 34 11 86 87 24 73 63 13 97 14 36 56 84 76 23 79  1 21  6
-Card 109: 29 20 25 51 48 41 97 49 58 77 | 14 77 99 41  5 93 56 44 71 52 31 34 87 65 53 78 40 49 36  7 51 25 66 97 29
-Card 110: 84  9 27 40 57 43 99 54 53 50 | 16 56 14 99 53 37 69 27 40 36 43 92 57 84  9 48 50 54 60 67 18 83  5 51  8
-Card 111: 51 69 84 37 64 21 24 93 57 33 | 78 72 14 33 93 57 97 48 26 20 92 69 53 89 24 21 84 80 45 63 64 51 37 96 90
-Card 112: 49 22 16 39 66 60 35 83 75 50 | 76 96 97 22 45 56 36 54 50 39 93 60 35 63 77 87 81  5 48 16 49 66 83 75 23
-Card 113: 25 79 14 45 58  1 40 16 28 12 | 27 34 11 45 53 25 52 47 79 28 93 39 56 95 58 32 51 40 73 12 14  1  3 24 49
-Card 114: 85 84  5 43  7 34 21 10  6 20 | 99 32 59 31 58 76 74 37 89 93 45 39  9 41 60 64 85 42 19 25 18 52 26 23 21
-Card 115: 17 19 72 49 98 78 81 99 95 39 | 76 45 27 68 18 67 88 99 96 95 31 23 98 17 81 77 72 20 12 54 49 74 39 19 78
-Card 116: 98 88 94 75 11 10 40 32 53 24 | 75 65 14 61 42 28  3 15  5 66 52 88  7 98 44 33 90 49 19 64 18  4 87 29 96
-Card 117: 44 47 63 70 32 78 41 39 46 98 | 54 48 97 88 80 84 50 60 78 82 63 71 44  7 56 94 22  9 32 68 27 36 28 83 65
-Card 118: 74 33 42 66 45  4 44 37 68 19 | 16 72 54 21 38 43 18 11 64 50 10 83 45 35  5 17 80 84 58 77 75 36 28 65 24
-Card 119: 55 51 89 74 66 40  7 10 85 26 | 99 70 53 24 20 84  3 17 74 15 13 85 80 88 23 38  8 59  2 96 19 35 66 81 41
-Card 120: 19  3 65 70  9 57 63  4 17 41 | 21 76 62 36 87 71 16  7 12 32 31 90 61 68 64 44 34 27 45 10 98 48 51 60 39
-Card 121:  6 77 99 31 98 33 36  4 52  1 | 86 66  8 73 70 89 45 24 50 72 25 21 82 41 94 64 65 10 91 55  4 52 92 68 63
-Card 122:  4 13 62 98 76 83  5 53 10 81 | 69 83 65 60 37 26 48 24  8 25  1 92 44 53 16 80 87 89 41 96 40 88 35  3 17
-Card 123: 18 26 93 87 27 72 34 94 44  8 | 50 99 10  1 90 32 25  6 87 97  2  9 88 21 34 43 12 96 42 24 20 45 83 86 74
-Card 124: 81 19 80 41 11  4 64 23 94 24 | 65 22 90  2 74 58 34  5 46 71 76  1 54 92 16 73 52 49 35  7 45 62 75 59 99
-Card 125: 80 63 19 16 87 97 15 96  3 57 | 41 82 66 25 60 58 72 32 54 23 86 71 65  6 94 13 59 99 62 85 43 18  7 75 74
-Card 126: 50 84 95  6 93 73 39 96 14 51 | 17 21 35  4 48 23 25 64 58 28 90 13  8 85 70 12 81 82  3 31 71  9 62 92 97
-Card 127: 47 68 65 98 82 66 34 49 24 23 | 84 64 68 42 23 65 54 33 22 48 35 82 34  9 89 12 24 47 49 58 66 87 98 73 85
-Card 128: 43  3 80 40 68 17 13 52 38 79 | 40 64 42 59 58 50 80 88 38 31 94 75 83 29  3 67 46 18  5 11 22 54 36 55 99
-Card 129: 95 76  7 43 87 50 20 12 97 73 | 25 73 12 87 35 21 10 56  7 49 95 76  3  5 50 45 43 61 51 99 23 20 93 97 84
-Card 130: 37 64 50 45 27 61 11  6 82 31 | 71 78 50 44 36 98 51 84 31 15 60 94  2 64 43 63 74 82 47 46  3 54 89 27 70
-Card 131: 18 65 42 31 80  2 17  5 27 61 | 48 11 86 92 96 40 17 30 22 71 34 13  2 61 33 31 50 26 64 56 18 45  9 67 36
-Card 132: 54 61 43 79 63 16 19 30 98  5 | 75  5 20 23 41  7 98 51 67 81 58 85 60 77 43 39 79 34 54 28 38 16 61 12 18
-Card 133: 64 53 25 17 56 43 11 22 86 51 | 41 64 47 25 43 20 52 75 46 17 60 56 50  6 23 61 86 22 24 53 95 11 34 51 21
-Card 134: 46 33 93 42 54 43 56 86 81 28 | 56 77 50 28 61 32  1 85 38 14 18 90 74 71 84  6 46 86 30 15 40 54 93 39 58
-Card 135: 53 61 62 48 52 91 31 80 77 29 | 40 88 38 34 12 18 19 49 75 97  2 71 69 94 55 36 89 33  9 76  3 46 90  1 67
-Card 136:  8 94 61 34 35 88  5 73 62 91 | 30 23 88 68 15 73 49  6  4 99 51 45 13 86 54 39 18 20 48 56 78 82 79 92 21
-Card 137: 33  3 71  8 79 46 57 65 53 77 | 46  6 33 56 84 77 53 95  8 57 65 44 66 35 75 72 28 34 88  3 14 42 94 79 71
-Card 138: 98 35 38 97 74 33 24 73 25 56 |  4 68 98 42 18 51  7 92 77 67 85 50 71 26 37 82 75  9 55 99 97 22 31 57 66
-Card 139: 40 56 54 71 41 88 22  9 99 21 | 93 99 18  1 42 44 54 70 58 36 83 57 40 63 78 87 80 49 71 92 31 21 17 33 94
-Card 140: 80 14 93 64  6 89 66 30 11  4 | 82 48 52 20 98 70 37 66 27 11 94 97 88 95 64 93 56  6 84 74 77 99  9 23  3
-Card 141: 46 88 36 26 23 40 63 55 41  5 | 89 25 56  2 39 72 38 46  5 90 84 81 64 33 86  7 27 88 45 52 23 77 70 41 42
-Card 142: 37 43 60 46 95 21 73 29 53 40 | 76 43 26  7 73 60 55 75 53 52 67 17 46  4 48  5 30 99  9 21 71 23 16 92 14
-Card 143: 97 73 49 55 41 69 45 30 39 37 | 31 84 24 56 99 76 93 74 53 94  8 12  5 19 92 58 33 75 85  1 96 54  7 14 98
-Card 144: 50 38 27 93 31 78 91 68 56 25 | 46 32 52 38 93 78 91 71 55 22 20 77 75 27 88 72 26 23  7 62 81 89 42 53  8
-Card 145: 37 56 94 46 96 50 78  3 38 64 | 64  5 21 63 68 59 57 41 87 37 49 65 78 88 10 23 56  7  9 42  2 11 75 70 14
-Card 146: 57 16 60 70 79 40 93 65 81 46 | 35 81  9 54 13 77 73 28 84 32 10 24  4  3 25 86  7 78 71 27  6 68 43 44 15
-Card 147: 93 96 19 16 30 97 76  7 72 39 |  5 67 73 71 65 27 11 56 90 24 61 95 19 59 99 29 10 49 53 44 72 64 35 21 91
-Card 148:  9 66 77 22 42  7 41 21 16 11 | 28  2 46 92 19 61 43 94 10 81 86 48 54 70 29 72 27 25 82 83 38 17 90 60 35
-Card 149: 91  8 16 79 15 45 96 87 13 66 | 42 51 61 98 38 57 80 24 82  3 17 79 34 48 37  6 46 81 74 85 50 25 68 26 69
-Card 150: 75 77 89 43 15 92 34 73 98 51 | 66 58 46 28 24 11  7  1 84 71 93 70 83 44 17 35 14 10 33 60 19 81 49 22 87
-Card 151: 99 39 20 81 55 45 87 53 68 74 |  8 87 23 15 45 74 67 61 26 81 75 17 20 99 55  3 39 68 53 52 29 43 62 11  6
-Card 152: 57  3 62 92 88 56 44  6 38 34 | 66 57  4 98 86 95 92 34 14 72 88  3 47 39 56  6 36 55 24 62 67 91 44 38 22
-Card 153: 98 31 95 27 99 58 73 65  2 67 | 38 86 54 75 56 90 68  1 63 39 55 77 23  9 19 43 78 79 64 66 13 61 14 74 80
-Card 154: 51 62 35 83 72 68 54 18 80 43 | 90 44 68 96 12 10 67 38 34 79 88 13 40 83 29 51 74 72 80 59 35 21 62 28 36
-Card 155: 33 54 10 41 20 48 27 89 58  2 | 19 58 54 33 87  5 51 96 20 27 12 80 48 89 53  2 10 69 81 50 26 41 30 29 15
-Card 156: 84 53 80 43  7 31 81 86  2 68 | 76 35 12 19  4 94 45  1 38  7 50 31 47 92 80 20 73 16 81 34 99 43 85 64 98
-Card 157:  8 92 37 14 34 70 49 13 62 59 | 24  8 94 37 14 13 55 44 98  7 78 60 70 23 56 75 21 43 88 10  6 71 81 92 29
-Card 158: 42 80 17 98 94 52 97 45 12 53 | 32 98 37 40 95 12  1 38 24 69 39 68 64 35 41 25 93 58  3 42  5 45 11 15 52
-Card 159: 91 43 78 95 29 99  4 15 35 66 | 78 28  4 17 65 30 36 92 35 85 40 33 52 91 29 55 99 23 54 69 19 43 95 15 66
-Card 160: 37 74 91 58 54 38 75 12 23 20 | 87 63 73 77 93  5 34 10 30 66 89 26  7 70 32 21 67 80 47  2 88 54 50 69 20
-Card 161: 18 22 90 63 47 93 77  3 69 43 | 47 88 64 84 41 25 85 12 63  3 82 33 62 92 45 93 94 98 69 86 81 42 65 44  9
-Card 162: 72 56 64  9 87 12  6 35 95 18 | 44 12 32  9 91 53 28 10 40 52 11 76 22 81  5 49 55 86 45 14  4 25 46 63 57
-Card 163: 10 54 97 72 18 14 28  7 59 57 | 32 97 49 55 42 52 78 61 88 38 39  5 36 95 25 59 13 14 65 19 43  1  8 18 51
-Card 164: 76  9 53 31 64 69 85 18 12 93 | 38 78 26 53 69 52 93 70 64 95 31  8 41 87 22 12 24  9 21  7 60 35 10 16 76
-Card 165: 28 70 83 80 25  9 41 66 98 62 | 23 82 94 21 85 32 22 44 56 96 16 71 10 34 64 87  5 92 75 31 91 48 84 39 17
-Card 166: 24 95  7 69 86 25 92 46 58  5 | 36 34 81 61 73 59 31  7 86 95 60 66 41  5 69 47 14 56 82 22 17 57 33  6 91
-Card 167: 32 87 68 97 19 13 35 58  3 56 | 32 77  6 36 73 46  8 55 25 67  5 47 97 30 17 80 92 59 39 91 29 34 86 70 33
-Card 168: 60 42 11  2 69 36 93 94 73 49 | 43 27 21 54 13 51 37 95 10 69 28  4 80 66 17 35 85 91 44 15 45 68  5 63 75
-Card 169: 10  8 68 48 56 86 54 16 43 28 | 21 60 40 31  8 44 71 76 15 84 68 39 61  9 33 51 79 11 26 94 66 97 35 54 37
-Card 170: 55 89 61 59 79 83 15  6 81 92 | 79 68 37 92 49 23 28 77  3 81 62 64 19 72 47 43  8 82 17 99 31 32 88 90  4
-Card 171: 33 80 93 38 44 21 77 48 60 12 | 47 12 62 96 15 86 42 76 75 19 70 25 31 98 24 58 13 56 66 88 26 40  1 91 57
-Card 172: 24 28 37 98 12 95 17 91 11 86 |  6 78  2 73 51 94 66 47 57 91 63 83 49 56 36  1 20 64 44 41 45 19 74 14 53
-Card 173: 29  6 78 72 98 44 75 79 50  3 | 80 20 99 39 48 59 14 88 15 38 65 51  8 25 94 68 18 90 93  1 71 85 35 34 19
-Card 174: 98 56 12  5 84 13 93 83 73 58 | 47 53 75 62 13 63 93  7 76 91 28  5 73 98 58 21 85 12 11 14 56 22 25 35 83
-Card 175: 47  1 24 46 98 41 95 69 73 49 | 95  1 98 11 56 46 48 24 41 69  8 14 61 64 45 73 47 84 89 67 31 77 25 49 28
-Card 176:  5 15 51 75 84  9 89 82 68 97 | 38  3 84 30 72 67 22 97  5  6 75 14 87 44 65 89 37 15  9 51 39 82 27 68 34
-Card 177: 60 16 73 50 47 54 28 11 71 49 | 66 50 73 34 89 54 57 59 56 11 60 79 16 19 10 86 71 28 47 49 63 43 38 46 25
-Card 178:  5 81 28 25 33 71 88 92 41 46 | 25 46 33 91 96  6 12 52 48 39 78  5 88 73 41 71 76 28 42 92 94 81 98 26 93
-Card 179: 73 75 39 27  6 58 32 56 76 86 | 73 11 88 78 22 74 75 27 86 72  6 57 68 39 71 56 58 29 20 89  1 42 61 76 32
-Card 180: 46 13 94 98 89 97 11 27 38  7 | 72  2 98 13 89 48  1 32 94 53 97 92 40 20 60 15 58 16 10 77 11 35 46 27 38
-Card 181: 43 11 50 81 67 39 94 54  9 20 | 81  2 52 36  1 95 49 24 84 64 10  8 28 22 17 63 46 88  6 54 50 68 93 91 57
-Card 182: 92 21 50 19 63 48 70 53 43 91 | 52 51 81 66 79 18 74 68 87 33 20 17 78 27 47 57 65  8 12 46 25 48 50 61 99
-Card 183: 83 57 14 93 56 58 12 28 23 52 | 52 74 25 15 99 17 85 90 28 42 81 14 75 56 31 11 46 37 57 50 83 12 58 93 23
-Card 184: 52 28 15  6 64 65  4 57 47 11 | 78 83 12 11 48  8 73 77 85 68 54 90 60 95 98 37 46 10 22 61 40 62 64 92 74
-Card 185: 46 47 29 12 74 64 48 91 17 57 | 43 74 11 44 69 47 62 46 23 80 65 35 64 48 76  6 57 60 54 13 16 79  9 71 30
-Card 186: 18  4 76 47  9 55 71 28 54 26 | 47 42 18 71 62 30 49 54  4 57  9 39  7 51 76 19 55 93 20 92 74 26 28 45 65
-Card 187: 63 13  6 78 71 25 21 93 23 69 |  8 69 76 71 60 23 93 49 78 13 70 51 35 61 52 94  6 63 31 66  3 77 25 21 88
-Card 188: 53  9 33 79 12 76 45 96 14 73 | 96 38 13 50 85 76 27 30 79 91 70 33 73  4 14  9 60  5 45 53  2 47 12 98 42
-Card 189: 79 96 84  3 20 34 81 60 90 61 | 63 34 18 66  9 27 67 45 31 60  8 35 76 61 90 20 68 38 96 84 74 72 55 86 50
-Card 190: 15  2 62 58 27 51 48 83 24 73 | 92 14 99 10 54  3 34 69 56 84 25 80 64 96 30 18 38 61 50 70  6 16 95 63  4
-Card 191: 13 27 45 73 70 71 14 41  1 44 | 62 72 42 50 81 84 94 71 19 39 95 99 83  9 48 53 11 31  2 90 41 59 49 91 29
-Card 192: 34  8 47 14 17 76 67 77 84 37 | 45  4 79 44 39 32 80 43 81 60 31 97 68 82  2  1 67 92 33 88 58 71 65  8 49
-Card 193: 35 66 23 67 26 51 43 97 93 38 | 75 48 27 37 25  1  8 80 39 20 40 61 42 99 83 10 74 65 56 21 28 57 89 24 68
-Card 194: 70 85 93 67 36 43 44 13 97 19 | 92 64  4 99 29 56 32 96 66 37 84 82 48 23 80 91 40 49 51  1 12 54 58 15  9
-Card 195: 50 66 31 16 57 32 69 52 93 92 | 49 18 66 95  7 90 34  5 43 46 67 16 33 89 26 24 77 11 94 38 70 45 51 42 55
-Card 196: 66 51 20 55 72 46 56 61  4 18 | 17 93 83 77 50 42 25 12  8 73 18 21 87 48 45 36 30 59 78 47 38 10 24 98  1
-Card 197: 16 39 31 67 74 41 36 85 10  8 | 66 50 24 93 20  5 25 80 99 73  3 83 71  4 81  2 10 32 89 28 13 37 82 52 88
-Card 198: 10 84 73 59  1  2 61 92 56 88 | 72 66 16 83 46 67 57 86  9 52 13 44 49 11 97 45 91 29 89 33 81 71 76 18 93
-Card 199:  4 16 88 10 75 98 93 56 81 89 | 52 63  1 42 78 91 58  2 66 82 76 17 28  6 16 85 93 77 34 75 72 88 89 96 68
-Card 200: 15 76 53 28 70  2 63 83 23 45 | 40 71 64 88 31 96 10 57  6 36 53 67 13 24 84 20 42 16 29 27 39  1 63 91 51
-Card 201: 16 46 59 31 80 26 78 19 24 95 | 58 33 31 97 80 54 59 60 46 63 26 42 84 78 40 95 88 24 16 43 19 45 62  3 83
-Card 202: 58 41 26 24 62 79 90 39 78 83 | 39 75 45 79 58 80  8 26 11 35 90 25 41 54 53  6 83 78 46 62 68 24 47 99 37
-Card 203: 14 90 66 42 57 26 24  1 84  2 | 71 45 57 66 24 75 14 90 84 26 64 44 42 15 55 78  2  1 69 30 13 19 25 11 91
-Card 204: 47 90 95 61 25 50 32  3 31 57 | 39 98 15 30 40 82 81 79  4 46  3 26 32 97 31  8 76 69 53 20 33 47 99 55 48
-Card 205: 29 18 52 42 30 84 90 94 46 44 | 68 94 12 24 74 98 44 34 42 30 85 66 52 40 32 14 22 87 84 46 26 35 31 29 90
-Card 206: 53 59 99 33 81 60 85 58 43 77 | 90 77 95 92 57 79 59 60 31  5 37  8 44 35 33 73 69 63 23 16 17 13 25 50 58
-Card 207:  9 91 26 59 87 90 22 12 51 99 | 40  9 85 99  1 11 93 61 56 82 90 91 59  6 12 31 86 76 51 87 92 35 19 34 54
-Card 208: 54 92 81 40 30 51 72 70 84 52 | 30 76 74 92  7 81 51 21 35 99 77 80 94 78 16 86 70 31 46 90 44 14  6 52 40
-Card 209: 49 44  6 38 33 83 75 95 39 72 | 46 30 75 17  1 28 15  9 44  6 16 89 78 22 95  2 72 59 38 36 25 43 42 41 67
-Card 210: 10 40 91  1 46 69 92 45 31 41 | 35  1 55 31 30 14 96 91 92 69 45 20 40 77 15 82 17 79 46 44 56 23 53 38 73
-Card 211: 67 27 31 84 61 64 58 68 21 74 | 21 52 88  6 73 36 58 89  1 46 18 17 86 85 28 23 27 25 29 78 79 50 90 30 70
-Card 212: 61 14 31  4  8 43 52 37 56 45 | 70 90 64 58 99 82 21  3 38 74 67 59 17 86 36 51 49 69 68 45 71 10 72  1 20
-Card 213: 51 69 54 42 23 68 27 98 47  4 | 20 99 66 15 61 43 62  3 60 75 90 47 16 40 80 51 13 87 83 94 49 42 69 74 24
-Card 214: 38  6 78 12 88 14 51 82 29 75 | 70 16 48 74 19 29  5 71 40 93  1 18 83 27 76 59 44 63 58 64 69 23 73 65 54
-Card 215: 67 60 86 35 17 62 55 27 54 70 |  9 26 45 56 43 72  1 32 25 88  5 87 73 81  2 34 57 13 61  3 84  4 33 92 59
-Card 216: 18 71  4 89 17 31 63 28 25 20 | 67 97  6 76  3 95 30 75 99 26 27 32 21 73 86 31 59 98 85  1 54 93 94 69 66
-Card 217: 54 65 75 13 46  8 37 25 95 82 | 57 14 83 33 69 47 68 64  4 21 17 92  2 48 30 70 62 50 36  7 72 66 41 85 97
-Card 218: 68 97 66 41 88 16 65 31 23 63 | 29 67 55 64 91  4 12 83  1 40 74 94 58 81 98 82 78 70 26 34 96 14 36 50 56
-"#;
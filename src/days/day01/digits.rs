@@ -0,0 +1,106 @@
+//! Finding written digits eight bytes at a time, for vocabularies that
+//! only have written digits in them.
+
+/// Which of the ASCII digits `0`-`9` count, one bit each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct DigitSet(u16);
+
+const ONES: u64 = u64::MAX / 255;
+const HIGH: u64 = ONES * 0x80;
+
+impl DigitSet {
+    /// The set matching `tokens` if they're all written digits standing for
+    /// themselves, otherwise nothing.
+    pub fn of<'t>(tokens: impl IntoIterator<Item = (&'t str, i32)>) -> Option<Self> {
+        let mut set = 0;
+        for (token, value) in tokens {
+            match token.as_bytes() {
+                [b @ b'0'..=b'9'] if (b - b'0') as i32 == value => set |= 1 << value,
+                _ => return None,
+            }
+        }
+        Some(DigitSet(set))
+    }
+
+    pub fn value(self, byte: u8) -> Option<i32> {
+        let digit = byte.wrapping_sub(b'0');
+        (digit < 10 && self.0 & (1 << digit) != 0).then_some(digit as i32)
+    }
+
+    /// Offset of the first digit in `bytes`.
+    pub fn first(self, bytes: &[u8]) -> Option<usize> {
+        let mut chunks = bytes.chunks_exact(8);
+        for (i, chunk) in chunks.by_ref().enumerate() {
+            let mut mask = ascii_digits(chunk);
+            while mask != 0 {
+                let at = mask.trailing_zeros() as usize / 8;
+                if self.value(chunk[at]).is_some() {
+                    return Some(i * 8 + at);
+                }
+                mask &= mask - 1;
+            }
+        }
+        let tail = bytes.len() - chunks.remainder().len();
+        chunks
+            .remainder()
+            .iter()
+            .position(|b| self.value(*b).is_some())
+            .map(|at| tail + at)
+    }
+
+    /// Offset of the last digit in `bytes`.
+    pub fn last(self, bytes: &[u8]) -> Option<usize> {
+        let mut chunks = bytes.rchunks_exact(8);
+        for (i, chunk) in chunks.by_ref().enumerate() {
+            let mut mask = ascii_digits(chunk);
+            while mask != 0 {
+                let high = 63 - mask.leading_zeros();
+                let at = high as usize / 8;
+                if self.value(chunk[at]).is_some() {
+                    return Some(bytes.len() - (i + 1) * 8 + at);
+                }
+                mask &= !(1 << high);
+            }
+        }
+        chunks
+            .remainder()
+            .iter()
+            .rposition(|b| self.value(*b).is_some())
+    }
+}
+
+/// The high bit of each byte of the chunk that's an ASCII digit. Exact for
+/// every byte value; see "Determine if a word has a byte between m and n"
+/// in Sean Anderson's Bit Twiddling Hacks.
+fn ascii_digits(chunk: &[u8]) -> u64 {
+    let x = u64::from_le_bytes(chunk.try_into().expect("chunks are eight bytes"));
+    let (below, above) = (b'0' as u64 - 1, b'9' as u64 + 1);
+    let low = x & (ONES * 127);
+    ((ONES * (127 + above) - low) & !x & (low + ONES * (127 - below))) & HIGH
+}
+
+#[test]
+fn finds_digits_in_every_position() {
+    let set = DigitSet::of(super::Vocabulary::digits().tokens()).unwrap();
+    for len in 0..20 {
+        for at in 0..len {
+            let mut line = vec![b'x'; len];
+            line[at] = b'7';
+            assert_eq!(Some(at), set.first(&line));
+            assert_eq!(Some(at), set.last(&line));
+        }
+        assert_eq!(None, set.first(&vec![b'/'; len]));
+        assert_eq!(None, set.last(&vec![b':'; len]));
+    }
+
+    let line = "0ab1cdefgh€ijk2lmnop0".as_bytes();
+    assert_eq!(Some(3), set.first(line));
+    assert_eq!(Some(16), set.last(line));
+}
+
+#[test]
+fn only_digit_vocabularies_have_a_set() {
+    assert!(DigitSet::of([("1", 1), ("0", 0)]).is_some());
+    assert!(DigitSet::of([("1", 2)]).is_none());
+    assert!(DigitSet::of([("1", 1), ("one", 1)]).is_none());
+}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::err::ParseError;
+
+use super::{calibration_value, LineParseError, Matcher};
+
+/// Which digits of a line make up its calibration value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Extraction {
+    /// The first and last digits as a two-digit number, as the puzzle asks.
+    /// A lone digit is used for both.
+    #[default]
+    FirstLast,
+    /// Every digit, in order, as one number.
+    Concatenated,
+    /// Up to this many digits from the start of the line, as one number.
+    FirstK(usize),
+    /// Up to this many digits from the end of the line, as one number.
+    LastK(usize),
+    /// The digits added up.
+    Sum,
+}
+
+impl FromStr for Extraction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let count = |k: &str| {
+            k.parse()
+                .ok()
+                .filter(|k| *k > 0)
+                .ok_or_else(|| ParseError::new("Malformed digit count", s))
+        };
+        match s.to_ascii_lowercase().as_str() {
+            "first-last" => Ok(Self::FirstLast),
+            "all" => Ok(Self::Concatenated),
+            "sum" => Ok(Self::Sum),
+            other => match other.split_once(':') {
+                Some(("first", k)) => Ok(Self::FirstK(count(k)?)),
+                Some(("last", k)) => Ok(Self::LastK(count(k)?)),
+                _ => Err(ParseError::new("Unknown digit extraction", s)),
+            },
+        }
+    }
+}
+
+impl Display for Extraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FirstLast => f.write_str("first-last"),
+            Self::Concatenated => f.write_str("all"),
+            Self::FirstK(k) => write!(f, "first:{}", k),
+            Self::LastK(k) => write!(f, "last:{}", k),
+            Self::Sum => f.write_str("sum"),
+        }
+    }
+}
+
+impl Extraction {
+    /// The value this extraction reads from `line`.
+    pub(super) fn value(self, matcher: &Matcher, line: &str) -> Result<u128, LineParseError> {
+        let digits: Vec<i32> = match self {
+            Self::FirstLast => return calibration_value(matcher, line).map(|v| v as u128),
+            Self::Concatenated | Self::Sum => matcher.matches(line).map(|m| m.value).collect(),
+            Self::FirstK(k) => matcher.matches(line).take(k).map(|m| m.value).collect(),
+            Self::LastK(k) => {
+                let mut digits: Vec<i32> =
+                    matcher.matches_rev(line).take(k).map(|m| m.value).collect();
+                digits.reverse();
+                digits
+            }
+        };
+        if digits.is_empty() {
+            return Err(LineParseError::NoDigitsFound);
+        }
+
+        match self {
+            Self::Sum => Ok(digits.iter().map(|d| *d as u128).sum()),
+            _ => digits
+                .iter()
+                .try_fold(0u128, |n, d| n.checked_mul(10)?.checked_add(*d as u128))
+                .ok_or(LineParseError::TooManyDigits(digits.len())),
+        }
+    }
+}
+
+#[test]
+fn extractions_pick_different_digits() {
+    let matcher = Matcher::new(&super::Vocabulary::english());
+    let line = "x4oneight7two";
+    let value = |extraction: &str| {
+        Extraction::from_str(extraction)
+            .unwrap()
+            .value(&matcher, line)
+            .unwrap()
+    };
+
+    assert_eq!(42, value("first-last"));
+    assert_eq!(41872, value("all"));
+    assert_eq!(418, value("first:3"));
+    assert_eq!(1872, value("last:4"));
+    assert_eq!(41872, value("first:9"));
+    assert_eq!(4 + 1 + 8 + 7 + 2, value("sum"));
+}
+
+#[test]
+fn overlong_values_are_rejected() {
+    let matcher = Matcher::new(&super::Vocabulary::digits());
+
+    assert!(matches!(
+        Extraction::Concatenated.value(&matcher, &"9".repeat(40)),
+        Err(LineParseError::TooManyDigits(40))
+    ));
+    assert_eq!(
+        Ok(9),
+        Extraction::Sum
+            .value(&matcher, "9abc")
+            .map_err(|e| e.to_string())
+    );
+    assert!(Extraction::from_str("first:0").is_err());
+    assert!(Extraction::from_str("middle").is_err());
+}
@@ -0,0 +1,239 @@
+use crate::err::ParseError;
+
+use super::{CalibrationMode, Vocabulary};
+
+/// What a generated calibration document should look like.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recipe {
+    /// The same seed and recipe always give the same document.
+    pub seed: u64,
+    pub lines: usize,
+    /// Filler characters. Any that appear in a digit word are left out, so
+    /// the only digits in a document are the ones put there on purpose.
+    pub noise: String,
+    /// Longest run of filler between digits.
+    pub max_noise: usize,
+    /// Most digits, written or spelled out, on one line.
+    pub max_digits: usize,
+    /// Share of digits that are spelled out rather than written.
+    pub word_ratio: f64,
+    /// Share of spelled-out digits that run straight into another word
+    /// they overlap, like "oneight".
+    pub overlap_ratio: f64,
+}
+
+impl Default for Recipe {
+    fn default() -> Self {
+        Recipe {
+            seed: 0,
+            lines: 1000,
+            noise: ('a'..='z').collect(),
+            max_noise: 6,
+            max_digits: 5,
+            word_ratio: 0.5,
+            overlap_ratio: 0.2,
+        }
+    }
+}
+
+/// A generated document and the answers it was built to have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    pub text: String,
+    pub digits_only: u128,
+    pub digits_and_words: u128,
+}
+
+impl Document {
+    pub fn expected(&self, mode: CalibrationMode) -> u128 {
+        match mode {
+            CalibrationMode::DigitsOnly => self.digits_only,
+            CalibrationMode::DigitsAndWords => self.digits_and_words,
+        }
+    }
+}
+
+/// SplitMix64: small, fast and good enough to make test data.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, for small `n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn chance(&mut self, p: f64) -> bool {
+        ((self.next() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+}
+
+/// Build a document from `recipe`, keeping track of every digit placed on
+/// each line so the answers are known without reading it back.
+///
+/// Every line gets at least one written digit, so both parts can read it.
+/// Digits are always separated by filler unless they're a deliberate
+/// overlap, so no token can appear by accident across the join.
+pub fn generate(recipe: &Recipe) -> Result<Document, ParseError> {
+    let words: Vec<(String, i32)> = Vocabulary::english()
+        .tokens()
+        .filter(|(token, _)| token.len() > 1)
+        .map(|(token, value)| (token.to_owned(), value))
+        .collect();
+    let noise: Vec<char> = recipe
+        .noise
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && !c.is_whitespace())
+        .filter(|c| !words.iter().any(|(word, _)| word.contains(*c)))
+        .collect();
+    if noise.is_empty() {
+        return Err(ParseError::new(
+            "Noise alphabet only has characters used by digits",
+            recipe.noise.clone(),
+        ));
+    }
+    let overlaps = overlapping_pairs(&words);
+
+    let mut rng = Rng(recipe.seed);
+    let mut document = Document {
+        text: String::new(),
+        digits_only: 0,
+        digits_and_words: 0,
+    };
+    for _ in 0..recipe.lines {
+        let count = 1 + rng.below(recipe.max_digits.max(1));
+        let written = rng.below(count);
+        // digits as read by each part, in order
+        let mut only: Vec<i32> = vec![];
+        let mut all: Vec<i32> = vec![];
+
+        let filler = |rng: &mut Rng, text: &mut String, at_least: usize| {
+            let len = at_least + rng.below(recipe.max_noise + 1);
+            text.extend((0..len).map(|_| noise[rng.below(noise.len())]));
+        };
+
+        filler(&mut rng, &mut document.text, 0);
+        for i in 0..count {
+            if i > 0 {
+                filler(&mut rng, &mut document.text, 1);
+            }
+            if i == written || !rng.chance(recipe.word_ratio) {
+                let digit = 1 + rng.below(9) as i32;
+                document.text.push(char::from(b'0' + digit as u8));
+                only.push(digit);
+                all.push(digit);
+            } else if !overlaps.is_empty() && rng.chance(recipe.overlap_ratio) {
+                let (joined, first, second) = &overlaps[rng.below(overlaps.len())];
+                document.text.push_str(joined);
+                all.extend([*first, *second]);
+            } else {
+                let (word, value) = &words[rng.below(words.len())];
+                document.text.push_str(word);
+                all.push(*value);
+            }
+        }
+        filler(&mut rng, &mut document.text, 0);
+        document.text.push('\n');
+
+        let value = |digits: &[i32]| (digits[0] * 10 + digits[digits.len() - 1]) as u128;
+        document.digits_only += value(&only);
+        document.digits_and_words += value(&all);
+    }
+
+    Ok(document)
+}
+
+/// Every pair of words where the end of the first is the start of the
+/// second, joined up ("eight" and "two" make "eightwo"), as long as the join
+/// doesn't hide a third word.
+fn overlapping_pairs(words: &[(String, i32)]) -> Vec<(String, i32, i32)> {
+    let mut pairs = vec![];
+    for (a, first) in words {
+        for (b, second) in words {
+            let Some(overlap) = (1..a.len().min(b.len())).find(|&n| a.ends_with(&b[..n])) else {
+                continue;
+            };
+            let joined = format!("{}{}", a, &b[overlap..]);
+            let hidden = words.iter().any(|(w, _)| {
+                joined
+                    .match_indices(w.as_str())
+                    .any(|(at, _)| at != 0 && at != a.len() - overlap)
+            });
+            if !hidden {
+                pairs.push((joined, *first, *second));
+            }
+        }
+    }
+    pairs
+}
+
+#[test]
+fn documents_are_reproducible() {
+    let recipe = Recipe {
+        seed: 7,
+        lines: 50,
+        ..Recipe::default()
+    };
+
+    assert_eq!(generate(&recipe).unwrap(), generate(&recipe).unwrap());
+    assert_ne!(
+        generate(&recipe).unwrap().text,
+        generate(&Recipe { seed: 8, ..recipe }).unwrap().text
+    );
+}
+
+#[test]
+fn answers_hold_by_construction() {
+    use crate::solution::Solution;
+
+    let document = generate(&Recipe {
+        seed: 2023,
+        lines: 20_000,
+        overlap_ratio: 0.5,
+        ..Recipe::default()
+    })
+    .unwrap();
+    let lines = super::Day01::parse(&document.text).unwrap();
+
+    assert_eq!(
+        document.digits_only,
+        super::Day01::part_one(&lines).unwrap()
+    );
+    assert_eq!(
+        document.digits_and_words,
+        super::Day01::part_two(&lines).unwrap()
+    );
+}
+
+#[test]
+fn overlaps_are_found() {
+    let words: Vec<(String, i32)> = [("one", 1), ("eight", 8), ("two", 2), ("three", 3)]
+        .iter()
+        .map(|(w, v)| (w.to_string(), *v))
+        .collect();
+    let joined: Vec<String> = overlapping_pairs(&words)
+        .into_iter()
+        .map(|(joined, _, _)| joined)
+        .collect();
+
+    assert_eq!(
+        vec!["oneight", "eightwo", "eighthree", "twone", "threeight"],
+        joined
+    );
+}
+
+#[test]
+fn noise_must_leave_something() {
+    assert!(generate(&Recipe {
+        noise: "one".to_owned(),
+        ..Recipe::default()
+    })
+    .is_err());
+}
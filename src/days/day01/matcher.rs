@@ -0,0 +1,159 @@
+use std::ops::Range;
+
+use super::digits::DigitSet;
+use super::Vocabulary;
+
+/// Finds vocabulary tokens in a line. The tokens are compiled into a byte
+/// trie once, so checking a position walks at most one path through it
+/// instead of comparing against every token in turn.
+///
+/// Matching is done on bytes, but a token can only match where a character
+/// starts (UTF-8 never starts a character with a continuation byte), so any
+/// text is safe to scan.
+///
+/// When the vocabulary is only written digits the trie isn't used at all:
+/// lines are searched eight bytes at a time for ASCII digits instead.
+#[derive(Debug, Clone)]
+pub struct Matcher {
+    nodes: Vec<Node>,
+    digits: Option<DigitSet>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    edges: Vec<(u8, usize)>,
+    value: Option<i32>,
+}
+
+/// A token found in a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// Byte offsets of the token in the line.
+    pub span: Range<usize>,
+    pub value: i32,
+}
+
+impl Matcher {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        Matcher {
+            digits: DigitSet::of(vocabulary.tokens()),
+            ..Matcher::trie(vocabulary)
+        }
+    }
+
+    /// A matcher that always walks the trie, even for a vocabulary of
+    /// written digits, to measure the fast path against.
+    pub fn trie(vocabulary: &Vocabulary) -> Self {
+        let mut nodes = vec![Node::default()];
+        for (token, value) in vocabulary.tokens() {
+            let mut at = 0;
+            for &byte in token.as_bytes() {
+                at = match nodes[at].edges.iter().find(|(b, _)| *b == byte) {
+                    Some(&(_, next)) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[at].edges.push((byte, next));
+                        next
+                    }
+                };
+            }
+            nodes[at].value = Some(value);
+        }
+        Matcher {
+            nodes,
+            digits: None,
+        }
+    }
+
+    /// The token starting at byte `start` of `line`, if there is one. When
+    /// several tokens start there the shortest is reported; the vocabulary
+    /// makes sure they all stand for the same digit.
+    pub fn match_at(&self, line: &str, start: usize) -> Option<Match> {
+        if let Some(digits) = self.digits {
+            return Self::digit_at(digits, line, start);
+        }
+        let mut at = 0;
+        for (offset, &byte) in line.as_bytes()[start..].iter().enumerate() {
+            at = self.nodes[at]
+                .edges
+                .iter()
+                .find(|(b, _)| *b == byte)
+                .map(|&(_, next)| next)?;
+            if let Some(value) = self.nodes[at].value {
+                return Some(Match {
+                    span: start..start + offset + 1,
+                    value,
+                });
+            }
+        }
+        None
+    }
+
+    fn digit_at(digits: DigitSet, line: &str, start: usize) -> Option<Match> {
+        digits.value(line.as_bytes()[start]).map(|value| Match {
+            span: start..start + 1,
+            value,
+        })
+    }
+
+    /// The earliest token in the line.
+    pub fn first(&self, line: &str) -> Option<Match> {
+        if let Some(digits) = self.digits {
+            let start = digits.first(line.as_bytes())?;
+            return Self::digit_at(digits, line, start);
+        }
+        (0..line.len()).find_map(|start| self.match_at(line, start))
+    }
+
+    /// The token starting latest in the line, found by scanning backwards
+    /// from the end so nothing before it is looked at.
+    pub fn last(&self, line: &str) -> Option<Match> {
+        if let Some(digits) = self.digits {
+            let start = digits.last(line.as_bytes())?;
+            return Self::digit_at(digits, line, start);
+        }
+        (0..line.len())
+            .rev()
+            .find_map(|start| self.match_at(line, start))
+    }
+
+    /// Every token in the line in order, overlapping ones included.
+    pub fn matches<'l>(&'l self, line: &'l str) -> impl Iterator<Item = Match> + 'l {
+        (0..line.len()).filter_map(move |start| self.match_at(line, start))
+    }
+
+    /// Every token in the line from the last back to the first.
+    pub fn matches_rev<'l>(&'l self, line: &'l str) -> impl Iterator<Item = Match> + 'l {
+        (0..line.len())
+            .rev()
+            .filter_map(move |start| self.match_at(line, start))
+    }
+}
+
+#[test]
+fn finds_first_and_last() {
+    let matcher = Matcher::new(&Vocabulary::english());
+
+    assert_eq!(Some(0..3), matcher.first("two1nine").map(|m| m.span));
+    assert_eq!(Some(4..8), matcher.last("two1nine").map(|m| m.span));
+    assert_eq!(None, matcher.first("xyz"));
+}
+
+#[test]
+fn overlapping_tokens_all_match() {
+    let matcher = Matcher::new(&Vocabulary::english());
+    let values: Vec<i32> = matcher.matches("oneightwo").map(|m| m.value).collect();
+
+    assert_eq!(vec![1, 8, 2], values);
+}
+
+#[test]
+fn scans_any_utf8() {
+    let german = Vocabulary::new([("eins", 1), ("zwei", 2), ("fünf", 5), ("5", 5)]).unwrap();
+    let matcher = Matcher::new(&german);
+
+    assert_eq!(Some(5), matcher.first("ü€fünfzwei").map(|m| m.value));
+    assert_eq!(Some(2), matcher.last("fünfzwei€ü").map(|m| m.value));
+    assert_eq!(None, Matcher::new(&Vocabulary::english()).first("ünë€"));
+}
@@ -0,0 +1,206 @@
+use std::fmt::Display;
+
+use crate::err::SolutionError;
+use crate::solution::Solution;
+use crate::trace;
+
+use tally::Tally;
+
+mod digits;
+mod extraction;
+mod generate;
+mod matcher;
+#[cfg(test)]
+mod reference;
+mod report;
+mod stream;
+mod tally;
+mod vocabulary;
+
+pub use extraction::Extraction;
+pub use generate::{generate, Document, Recipe};
+pub use matcher::{Match, Matcher};
+pub use report::{explain, report_table, Digit, Highlight, LineReport};
+pub use stream::solve_reader;
+pub use tally::{Calibration, MissingDigits};
+pub use vocabulary::Vocabulary;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    /// Each non-empty line with its line number. The digits are only
+    /// picked out once a part says which vocabulary to use.
+    type Input<'a> = Vec<(usize, &'a str)>;
+
+    type Answer = u128;
+
+    fn parse(s: &str) -> Result<Vec<(usize, &str)>, SolutionError> {
+        Ok(s.lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(idx, line)| (idx + 1, line))
+            .collect())
+    }
+
+    fn part_one(lines: &Vec<(usize, &str)>) -> Result<u128, SolutionError> {
+        calibrate(lines, CalibrationMode::DigitsOnly)
+    }
+
+    fn part_two(lines: &Vec<(usize, &str)>) -> Result<u128, SolutionError> {
+        calibrate(lines, CalibrationMode::DigitsAndWords)
+    }
+}
+
+/// Which tokens count as digits when reading a calibration document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationMode {
+    /// Only the digits as written. Part one.
+    DigitsOnly,
+    /// Digits and their spelled-out English names. Part two.
+    DigitsAndWords,
+}
+
+impl CalibrationMode {
+    pub fn vocabulary(self) -> Vocabulary {
+        match self {
+            CalibrationMode::DigitsOnly => Vocabulary::digits(),
+            CalibrationMode::DigitsAndWords => Vocabulary::english(),
+        }
+    }
+}
+
+/// How to read a calibration document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub mode: CalibrationMode,
+    pub extraction: Extraction,
+    pub missing: MissingDigits,
+    /// Worker threads for [`solve_reader`]; one reads on the calling thread.
+    pub threads: usize,
+}
+
+impl Options {
+    /// The puzzle's reading in `mode`: first and last digits, failing on any
+    /// line without digits.
+    pub fn new(mode: CalibrationMode) -> Self {
+        Options {
+            mode,
+            extraction: Extraction::default(),
+            missing: MissingDigits::default(),
+            threads: 1,
+        }
+    }
+}
+
+/// Sum the calibration values of every numbered line, failing on any line
+/// without digits.
+pub fn calibrate(lines: &[(usize, &str)], mode: CalibrationMode) -> Result<u128, SolutionError> {
+    Ok(calibrate_with(lines, &Options::new(mode))?.total)
+}
+
+/// Sum the values `options` reads from every numbered line.
+pub fn calibrate_with(
+    lines: &[(usize, &str)],
+    options: &Options,
+) -> Result<Calibration, SolutionError> {
+    let matcher = Matcher::new(&options.mode.vocabulary());
+    let mut tally = Tally::new(&matcher, options);
+    for &(number, line) in lines {
+        tally.add(number, line)?;
+    }
+    Ok(tally.finish())
+}
+
+/// The calibration value of a line: its first and last digits read as a
+/// two-digit number.
+fn calibration_value(matcher: &Matcher, line: &str) -> Result<i32, LineParseError> {
+    let first = matcher.first(line).ok_or(LineParseError::NoDigitsFound)?;
+
+    // problem statement _appears_ to guarantee that the numbers embedded on each line are 2-digit
+    // > On each line, the calibration value can be found by combining the first digit and the last digit (in that order) to form a single two-digit number.
+    // _however_ the first example immediately contradicts this:
+    // the last line of the input contains only a single digit, 7, but the explanation and
+    // provided correct value indicate we should instead treat that single 7 as both the
+    // first and second digits of a two digit number, i.e. "77." Scanning from the end
+    // finds that same 7 again, so this falls out without special casing.
+    let last = matcher.last(line).unwrap_or_else(|| first.clone());
+    let value = (first.value * 10) + last.value;
+    trace!(
+        "day01",
+        Info,
+        "{:?}: first {}, last {} -> {}",
+        line,
+        first.value,
+        last.value,
+        value
+    );
+    Ok(value)
+}
+
+#[derive(Debug)]
+enum LineParseError {
+    NoDigitsFound,
+    TooManyDigits(usize),
+}
+
+impl Display for LineParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineParseError::NoDigitsFound => f.write_str("No digits found in string"),
+            LineParseError::TooManyDigits(n) => {
+                write!(f, "{} digits are too many for one value", n)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LineParseError {}
+
+#[test]
+fn full_input() {
+    let parsed = Day01::parse(include_str!("../../../fixtures/day-01.txt")).unwrap();
+    assert_eq!(55971, Day01::part_one(&parsed).unwrap());
+    assert_eq!(54719, Day01::part_two(&parsed).unwrap());
+}
+
+#[test]
+fn modes_diverge() {
+    let parsed = Day01::parse("two1nine\n4nineeightseven2\n").unwrap();
+    assert_eq!(
+        11 + 42,
+        calibrate(&parsed, CalibrationMode::DigitsOnly).unwrap()
+    );
+    assert_eq!(
+        29 + 42,
+        calibrate(&parsed, CalibrationMode::DigitsAndWords).unwrap()
+    );
+}
+
+#[test]
+fn overlapping_words_both_count() {
+    let calibrate = |v: &Vocabulary, line| calibration_value(&Matcher::new(v), line).ok();
+    let english = Vocabulary::english();
+    assert_eq!(Some(82), calibrate(&english, "eightwo"));
+    assert_eq!(Some(18), calibrate(&english, "oneight"));
+    assert_eq!(Some(23), calibrate(&english, "xtwone3eightwothree"));
+}
+
+#[test]
+fn digits_only_ignores_words() {
+    let calibrate = |v: &Vocabulary, line| calibration_value(&Matcher::new(v), line).ok();
+    assert_eq!(Some(11), calibrate(&Vocabulary::digits(), "two1nine"));
+    assert_eq!(None, calibrate(&Vocabulary::digits(), "eightwothree"));
+}
+
+#[test]
+fn custom_vocabularies() {
+    let calibrate = |v: &Vocabulary, line| calibration_value(&Matcher::new(v), line).ok();
+    let german = Vocabulary::new([("eins", 1), ("zwei", 2), ("drei", 3), ("fünf", 5)]).unwrap();
+    assert_eq!(Some(53), calibrate(&german, "fünfzweidrei"));
+
+    // overlaps are found whatever the tokens look like
+    let aliases = Vocabulary::new([("aba", 4), ("bab", 7)]).unwrap();
+    assert_eq!(Some(47), calibrate(&aliases, "abab"));
+}
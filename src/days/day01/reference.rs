@@ -0,0 +1,138 @@
+//! A deliberately naïve reading of calibration lines, to check the real
+//! matcher against.
+
+use proptest::prelude::*;
+
+use super::{calibration_value, generate, Matcher, Recipe, Vocabulary};
+
+/// Every (start, value) pair where a token appears in the line, found by
+/// comparing every substring against every token.
+fn all_digits(vocabulary: &Vocabulary, line: &str) -> Vec<(usize, i32)> {
+    let mut found = vec![];
+    for start in 0..=line.len() {
+        for end in start..=line.len() {
+            let Some(candidate) = line.get(start..end) else {
+                continue;
+            };
+            if let Some((_, value)) = vocabulary.tokens().find(|(t, _)| *t == candidate) {
+                if found.last().map(|(s, _)| *s) != Some(start) {
+                    found.push((start, value));
+                }
+            }
+        }
+    }
+    found
+}
+
+fn reference_value(vocabulary: &Vocabulary, line: &str) -> Option<i32> {
+    let digits = all_digits(vocabulary, line);
+    let first = digits.first()?.1;
+    let last = digits.last()?.1;
+    Some(first * 10 + last)
+}
+
+/// Lines made mostly of tokens, pieces of tokens and letters that tokens
+/// use, so overlaps and near misses come up often.
+fn biased_line(vocabulary: Vocabulary) -> impl Strategy<Value = String> {
+    let tokens: Vec<String> = vocabulary.tokens().map(|(t, _)| t.to_owned()).collect();
+    let pieces: Vec<String> = tokens
+        .iter()
+        .flat_map(|t| {
+            let chars: Vec<char> = t.chars().collect();
+            (1..=chars.len()).flat_map(move |i| {
+                [
+                    chars[..i].iter().collect::<String>(),
+                    chars[i..].iter().collect::<String>(),
+                ]
+            })
+        })
+        .collect();
+    let piece = prop_oneof![
+        3 => proptest::sample::select(tokens),
+        2 => proptest::sample::select(pieces),
+        1 => "[a-z0-9é€]",
+    ];
+    proptest::collection::vec(piece, 0..12).prop_map(|pieces| pieces.concat())
+}
+
+/// Small vocabularies over a few letters, which overlap each other a lot.
+fn small_vocabulary() -> impl Strategy<Value = Vocabulary> {
+    proptest::collection::vec(("[abé]{1,4}", 0..10i32), 1..6)
+        .prop_filter_map("ambiguous vocabulary", |tokens| {
+            Vocabulary::new(tokens).ok()
+        })
+}
+
+#[test]
+fn reference_reads_the_examples() {
+    let english = Vocabulary::english();
+    assert_eq!(Some(82), reference_value(&english, "eightwo"));
+    assert_eq!(Some(77), reference_value(&english, "treb7uchet"));
+    assert_eq!(None, reference_value(&english, "abc"));
+}
+
+proptest! {
+    #[test]
+    fn agrees_with_reference_in_english(line in biased_line(Vocabulary::english())) {
+        let english = Vocabulary::english();
+        let matcher = Matcher::new(&english);
+
+        prop_assert_eq!(
+            reference_value(&english, &line),
+            calibration_value(&matcher, &line).ok()
+        );
+        let starts: Vec<(usize, i32)> = matcher.matches(&line).map(|m| (m.span.start, m.value)).collect();
+        prop_assert_eq!(all_digits(&english, &line), starts);
+    }
+
+    #[test]
+    fn agrees_with_reference_for_any_vocabulary(
+        (vocabulary, line) in small_vocabulary()
+            .prop_flat_map(|v| (Just(v.clone()), biased_line(v)))
+    ) {
+        let matcher = Matcher::new(&vocabulary);
+
+        prop_assert_eq!(
+            reference_value(&vocabulary, &line),
+            calibration_value(&matcher, &line).ok()
+        );
+    }
+
+    #[test]
+    fn generated_documents_read_as_built(
+        seed in any::<u64>(),
+        word_ratio in 0.0..=1.0f64,
+        overlap_ratio in 0.0..=1.0f64,
+    ) {
+        let document = generate(&Recipe {
+            seed,
+            lines: 20,
+            word_ratio,
+            overlap_ratio,
+            ..Recipe::default()
+        })
+        .unwrap();
+
+        for (vocabulary, expected) in [
+            (Vocabulary::digits(), document.digits_only),
+            (Vocabulary::english(), document.digits_and_words),
+        ] {
+            let total: i32 = document
+                .text
+                .lines()
+                .map(|line| reference_value(&vocabulary, line).unwrap())
+                .sum();
+            prop_assert_eq!(expected, total as u128);
+        }
+    }
+
+    #[test]
+    fn digit_fast_path_agrees_with_trie(line in "[0-9a-z€é:/]{0,40}") {
+        let digits = Vocabulary::digits();
+        let (fast, trie) = (Matcher::new(&digits), Matcher::trie(&digits));
+
+        prop_assert_eq!(trie.first(&line), fast.first(&line));
+        prop_assert_eq!(trie.last(&line), fast.last(&line));
+        prop_assert_eq!(reference_value(&digits, &line), calibration_value(&fast, &line).ok());
+    }
+}
@@ -0,0 +1,165 @@
+use crate::table::Table;
+
+use super::{CalibrationMode, Match, Matcher};
+
+/// How one line was read: where its first and last digits were found and
+/// the calibration value they make.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineReport<'a> {
+    pub line: usize,
+    pub text: &'a str,
+    pub first: Option<Digit<'a>>,
+    pub last: Option<Digit<'a>>,
+    /// Missing when the line has no digits at all.
+    pub value: Option<i32>,
+}
+
+/// A digit found on a line, as it was spelled there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Digit<'a> {
+    pub start: usize,
+    pub end: usize,
+    pub spelling: &'a str,
+    pub value: i32,
+}
+
+impl<'a> Digit<'a> {
+    fn new(text: &'a str, m: Match) -> Self {
+        Digit {
+            start: m.span.start,
+            end: m.span.end,
+            spelling: &text[m.span],
+            value: m.value,
+        }
+    }
+}
+
+/// How [`LineReport::highlight`] marks the matched digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// `[two]1[nine]`
+    Brackets,
+    /// Reverse video, for terminals.
+    Ansi,
+}
+
+impl<'a> LineReport<'a> {
+    /// The line's text with its first and last digits marked.
+    pub fn highlight(&self, style: Highlight) -> String {
+        let (open, close) = match style {
+            Highlight::Brackets => ("[", "]"),
+            Highlight::Ansi => ("\x1b[7m", "\x1b[0m"),
+        };
+        let (Some(first), Some(last)) = (&self.first, &self.last) else {
+            return self.text.to_owned();
+        };
+
+        // the two digits can be the same match, or overlap ("oneight")
+        let mut marked = String::new();
+        if first.end <= last.start || first == last {
+            marked.push_str(&self.text[..first.start]);
+            marked.push_str(&format!("{}{}{}", open, first.spelling, close));
+            if first != last {
+                marked.push_str(&self.text[first.end..last.start]);
+                marked.push_str(&format!("{}{}{}", open, last.spelling, close));
+            }
+        } else {
+            marked.push_str(&self.text[..first.start]);
+            marked.push_str(&format!(
+                "{}{}{}",
+                open,
+                &self.text[first.start..last.end],
+                close
+            ));
+        }
+        marked.push_str(&self.text[last.end.max(first.end)..]);
+        marked
+    }
+}
+
+/// Report how every numbered line is read in `mode`.
+pub fn explain<'a>(lines: &[(usize, &'a str)], mode: CalibrationMode) -> Vec<LineReport<'a>> {
+    let matcher = Matcher::new(&mode.vocabulary());
+    lines
+        .iter()
+        .map(|&(line, text)| {
+            let first = matcher.first(text).map(|m| Digit::new(text, m));
+            let last = matcher.last(text).map(|m| Digit::new(text, m));
+            let value = first
+                .as_ref()
+                .zip(last.as_ref())
+                .map(|(first, last)| first.value * 10 + last.value);
+            LineReport {
+                line,
+                text,
+                first,
+                last,
+                value,
+            }
+        })
+        .collect()
+}
+
+/// An aligned table of the reports, with the digits in each line marked
+/// when `highlight` is given.
+pub fn report_table(reports: &[LineReport], highlight: Option<Highlight>) -> String {
+    let mut table = Table::new(["line", "first", "at", "last", "at", "value", "text"]);
+    let digit = |d: &Option<Digit>| match d {
+        Some(d) => (d.spelling.to_owned(), format!("{}..{}", d.start, d.end)),
+        None => ("-".to_owned(), "-".to_owned()),
+    };
+    for report in reports {
+        let (first, first_at) = digit(&report.first);
+        let (last, last_at) = digit(&report.last);
+        table.row([
+            report.line.to_string(),
+            first,
+            first_at,
+            last,
+            last_at,
+            report
+                .value
+                .map_or("no digits".to_owned(), |v| v.to_string()),
+            highlight.map_or(report.text.to_owned(), |style| report.highlight(style)),
+        ]);
+    }
+    table.to_string()
+}
+
+#[test]
+fn reports_first_and_last_digits() {
+    let reports = explain(
+        &[(1, "two1nine"), (3, "treb7uchet"), (4, "abc")],
+        CalibrationMode::DigitsAndWords,
+    );
+
+    let first = reports[0].first.as_ref().unwrap();
+    let last = reports[0].last.as_ref().unwrap();
+    assert_eq!((0, 3, "two"), (first.start, first.end, first.spelling));
+    assert_eq!((4, 8, "nine"), (last.start, last.end, last.spelling));
+    assert_eq!(Some(29), reports[0].value);
+    assert_eq!((3, Some(77)), (reports[1].line, reports[1].value));
+    assert_eq!(None, reports[2].value);
+}
+
+#[test]
+fn highlights_matched_spans() {
+    let reports = explain(
+        &[
+            (1, "xtwone3four"),
+            (2, "treb7uchet"),
+            (3, "oneight"),
+            (4, "abc"),
+        ],
+        CalibrationMode::DigitsAndWords,
+    );
+    let marked: Vec<String> = reports
+        .iter()
+        .map(|r| r.highlight(Highlight::Brackets))
+        .collect();
+
+    assert_eq!(
+        vec!["x[two]ne3[four]", "treb[7]uchet", "[oneight]", "abc"],
+        marked
+    );
+}
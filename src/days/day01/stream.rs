@@ -0,0 +1,204 @@
+use std::io::BufRead;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use crate::err::SolutionError;
+
+use super::{Calibration, Matcher, Options, Tally};
+
+/// How many lines each worker thread is handed at a time.
+const BATCH_LINES: usize = 4096;
+
+/// Sum the calibration values of a document read incrementally, so inputs
+/// of any size can be processed without holding them in memory.
+///
+/// With more than one thread, lines are read here in batches and handed to
+/// `options.threads` workers; at most a couple of batches per worker are in flight
+/// at once. Invalid UTF-8 is replaced rather than rejected, and when several
+/// lines are invalid the earliest one is reported.
+pub fn solve_reader<R: BufRead>(
+    reader: R,
+    options: &Options,
+) -> Result<Calibration, SolutionError> {
+    let matcher = Matcher::new(&options.mode.vocabulary());
+    let mut tally = Tally::new(&matcher, options);
+    let threads = options.threads;
+    if threads <= 1 {
+        each_line(reader, |number, line| tally.add(number, line))?;
+        return Ok(tally.finish());
+    }
+
+    let (sender, receiver) = mpsc::sync_channel::<Vec<(usize, String)>>(threads * 2);
+    let receiver = Arc::new(Mutex::new(receiver));
+    thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                let matcher = &matcher;
+                scope.spawn(move || work(matcher, options, &receiver))
+            })
+            .collect();
+
+        let mut batch = Vec::with_capacity(BATCH_LINES);
+        let read = each_line(reader, |number, line| {
+            batch.push((number, line.to_owned()));
+            if batch.len() == BATCH_LINES {
+                // only fails if every worker has gone, which they don't
+                let _ = sender.send(std::mem::replace(
+                    &mut batch,
+                    Vec::with_capacity(BATCH_LINES),
+                ));
+            }
+            Ok(())
+        });
+        if !batch.is_empty() {
+            let _ = sender.send(batch);
+        }
+        drop(sender);
+
+        let mut first_error: Option<(usize, SolutionError)> = None;
+        for worker in workers {
+            let merged = match worker.join().expect("calibration worker panicked") {
+                Ok((calibration, last_line)) => tally
+                    .merge(calibration, last_line)
+                    .map_err(|e| (last_line, e)),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = merged {
+                first_error = earliest(first_error, e);
+            }
+        }
+
+        read?;
+        match first_error {
+            Some((_, e)) => Err(e),
+            None => Ok(tally.finish()),
+        }
+    })
+}
+
+/// Call `f` with each non-empty line and its line number.
+fn each_line<R: BufRead>(
+    mut reader: R,
+    mut f: impl FnMut(usize, &str) -> Result<(), SolutionError>,
+) -> Result<(), SolutionError> {
+    let mut buf = vec![];
+    let mut number = 0;
+    loop {
+        buf.clear();
+        if reader.read_until(b'\n', &mut buf)? == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let line = String::from_utf8_lossy(&buf);
+        let line = line.trim_end_matches(['\n', '\r']);
+        if !line.is_empty() {
+            f(number, line)?;
+        }
+    }
+}
+
+type Batches = Mutex<mpsc::Receiver<Vec<(usize, String)>>>;
+
+/// Tally every batch a worker is handed, returning the result and the last
+/// line it saw, or the earliest line it couldn't use.
+fn work(
+    matcher: &Matcher,
+    options: &Options,
+    batches: &Batches,
+) -> Result<(Calibration, usize), (usize, SolutionError)> {
+    let mut tally = Tally::new(matcher, options);
+    let mut last_line = 0;
+    let mut first_error = None;
+    loop {
+        let batch = match batches.lock().expect("batch queue poisoned").recv() {
+            Ok(batch) => batch,
+            Err(_) => break,
+        };
+        for (number, line) in batch {
+            last_line = last_line.max(number);
+            if first_error.is_some() {
+                continue;
+            }
+            if let Err(e) = tally.add(number, &line) {
+                first_error = earliest(first_error, (number, e));
+            }
+        }
+    }
+
+    match first_error {
+        Some(e) => Err(e),
+        None => Ok((tally.finish(), last_line)),
+    }
+}
+
+fn earliest(
+    current: Option<(usize, SolutionError)>,
+    candidate: (usize, SolutionError),
+) -> Option<(usize, SolutionError)> {
+    match current {
+        Some(current) if current.0 <= candidate.0 => Some(current),
+        _ => Some(candidate),
+    }
+}
+
+#[test]
+fn matches_the_in_memory_answer() {
+    use crate::solution::Solution;
+
+    let text = include_str!("../../../fixtures/day-01.txt");
+    let lines = super::Day01::parse(text).unwrap();
+
+    for mode in [
+        super::CalibrationMode::DigitsOnly,
+        super::CalibrationMode::DigitsAndWords,
+    ] {
+        let expected = super::calibrate(&lines, mode).unwrap();
+        for threads in [1, 3] {
+            let streamed = solve_reader(
+                text.as_bytes(),
+                &Options {
+                    threads,
+                    ..Options::new(mode)
+                },
+            )
+            .unwrap();
+            assert_eq!(expected, streamed.total);
+        }
+    }
+}
+
+#[test]
+fn reports_the_earliest_bad_line() {
+    let text = "1a\n".repeat(10_000) + "abc\n" + &"b2\n".repeat(10_000) + "xyz\n";
+
+    for threads in [1, 4] {
+        let options = Options {
+            threads,
+            ..Options::new(super::CalibrationMode::DigitsOnly)
+        };
+        let err = solve_reader(text.as_bytes(), &options).unwrap_err();
+        match err {
+            SolutionError::ParseError(e) => assert_eq!(Some(10_001), e.line()),
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+}
+
+#[test]
+fn skipped_lines_are_collected_from_every_worker() {
+    let text = "1a\n".repeat(10_000) + "abc\n" + &"b2\n".repeat(10_000) + "xyz\n";
+
+    for threads in [1, 4] {
+        let options = Options {
+            threads,
+            missing: super::MissingDigits::Skip,
+            ..Options::new(super::CalibrationMode::DigitsOnly)
+        };
+        let calibration = solve_reader(text.as_bytes(), &options).unwrap();
+        assert_eq!(10_000 * 11 + 10_000 * 22, calibration.total);
+        assert_eq!(2, calibration.without_digits);
+        assert_eq!(vec![10_001, 20_002], calibration.first_without_digits);
+    }
+}
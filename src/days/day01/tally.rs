@@ -0,0 +1,228 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::err::{ParseError, SolutionError};
+
+use super::{Extraction, LineParseError, Matcher, Options};
+
+/// What to do with a line that has no digits on it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingDigits {
+    /// Leave the line out of the sum.
+    Skip,
+    /// Count the line as a calibration value of zero.
+    Zero,
+    /// Stop with an error naming the line.
+    #[default]
+    Abort,
+}
+
+impl FromStr for MissingDigits {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "skip" => Ok(Self::Skip),
+            "zero" => Ok(Self::Zero),
+            "abort" => Ok(Self::Abort),
+            _ => Err(ParseError::new("Unknown missing digit policy", s)),
+        }
+    }
+}
+
+/// How many of the lines without digits a [`Calibration`] keeps the
+/// numbers of.
+const SHOWN: usize = 10;
+
+/// The sum of a calibration document, along with the lines that had no
+/// digits and how they were treated. Only the first ten of those lines
+/// are named, so a calibration stays the same size however long
+/// the document is.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calibration {
+    pub total: u128,
+    pub missing: MissingDigits,
+    /// How many lines had no digits.
+    pub without_digits: usize,
+    /// The first of those lines' numbers, in order.
+    pub first_without_digits: Vec<usize>,
+}
+
+impl Calibration {
+    fn note_without_digits(&mut self, numbers: impl IntoIterator<Item = usize>) {
+        self.first_without_digits.extend(numbers);
+        self.first_without_digits.sort_unstable();
+        self.first_without_digits.truncate(SHOWN);
+    }
+}
+
+impl Display for Calibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = self.without_digits;
+        if count == 0 {
+            return f.write_str("every line had digits");
+        }
+        let verb = match self.missing {
+            MissingDigits::Zero => "counted as zero",
+            _ => "skipped",
+        };
+        let shown: Vec<String> = self
+            .first_without_digits
+            .iter()
+            .map(|l| l.to_string())
+            .collect();
+        write!(
+            f,
+            "{} {} without digits {}: {}",
+            count,
+            if count == 1 { "line" } else { "lines" },
+            verb,
+            shown.join(", ")
+        )?;
+        if count > shown.len() {
+            write!(f, " and {} more", count - shown.len())?;
+        }
+        Ok(())
+    }
+}
+
+/// A running calibration total, applying the missing digit policy as
+/// lines are added.
+pub(super) struct Tally<'m> {
+    matcher: &'m Matcher,
+    extraction: Extraction,
+    calibration: Calibration,
+}
+
+impl<'m> Tally<'m> {
+    pub fn new(matcher: &'m Matcher, options: &Options) -> Self {
+        Tally {
+            matcher,
+            extraction: options.extraction,
+            calibration: Calibration {
+                missing: options.missing,
+                ..Calibration::default()
+            },
+        }
+    }
+
+    pub fn add(&mut self, number: usize, line: &str) -> Result<(), SolutionError> {
+        let value = match self.extraction.value(self.matcher, line) {
+            Ok(value) => value,
+            Err(LineParseError::NoDigitsFound)
+                if self.calibration.missing != MissingDigits::Abort =>
+            {
+                self.calibration.without_digits += 1;
+                if self.calibration.first_without_digits.len() < SHOWN {
+                    self.calibration.first_without_digits.push(number);
+                }
+                0
+            }
+            Err(e) => {
+                return Err(ParseError::new("Invalid calibration line", line)
+                    .at_line(number)
+                    .caused_by(e)
+                    .into())
+            }
+        };
+        self.calibration.total = accumulate(self.calibration.total, value, number)?;
+        Ok(())
+    }
+
+    /// Fold in the tally of another part of the same document.
+    pub fn merge(&mut self, other: Calibration, number: usize) -> Result<(), SolutionError> {
+        self.calibration.total = accumulate(self.calibration.total, other.total, number)?;
+        // each part keeps its own first lines, so the first lines of the
+        // whole are among them
+        self.calibration.without_digits += other.without_digits;
+        self.calibration
+            .note_without_digits(other.first_without_digits);
+        Ok(())
+    }
+
+    pub fn finish(self) -> Calibration {
+        self.calibration
+    }
+}
+
+/// Add to a running total, failing rather than wrapping if it no longer fits.
+fn accumulate(sum: u128, value: u128, number: usize) -> Result<u128, SolutionError> {
+    sum.checked_add(value).ok_or_else(|| {
+        ParseError::new("Calibration total overflowed", value.to_string())
+            .at_line(number)
+            .into()
+    })
+}
+
+#[test]
+fn policies_treat_lines_without_digits() {
+    let matcher = Matcher::new(&super::Vocabulary::digits());
+    let lines = [(1, "a1b"), (2, "abc"), (4, "x9"), (5, "")];
+
+    let tally = |missing| {
+        let options = Options {
+            missing,
+            ..Options::new(super::CalibrationMode::DigitsOnly)
+        };
+        let mut tally = Tally::new(&matcher, &options);
+        lines
+            .iter()
+            .try_for_each(|&(number, line)| tally.add(number, line))
+            .map(|_| tally.finish())
+    };
+
+    let skipped = tally(MissingDigits::Skip).unwrap();
+    assert_eq!(
+        (11 + 99, 2, vec![2, 5]),
+        (
+            skipped.total,
+            skipped.without_digits,
+            skipped.first_without_digits.clone()
+        )
+    );
+    assert_eq!("2 lines without digits skipped: 2, 5", skipped.to_string());
+    assert_eq!(
+        "2 lines without digits counted as zero: 2, 5",
+        tally(MissingDigits::Zero).unwrap().to_string()
+    );
+
+    match tally(MissingDigits::Abort).unwrap_err() {
+        SolutionError::ParseError(e) => {
+            assert_eq!((Some(2), "abc"), (e.line(), e.text()));
+            assert!(e
+                .to_string()
+                .starts_with("line 2: Invalid calibration line"));
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+}
+
+#[test]
+fn only_the_first_lines_without_digits_are_kept() {
+    let matcher = Matcher::new(&super::Vocabulary::digits());
+    let options = Options {
+        missing: MissingDigits::Skip,
+        ..Options::new(super::CalibrationMode::DigitsOnly)
+    };
+    let part = |numbers: std::ops::Range<usize>| {
+        let mut tally = Tally::new(&matcher, &options);
+        for number in numbers {
+            tally.add(number, "none").unwrap();
+        }
+        tally.finish()
+    };
+
+    let mut tally = Tally::new(&matcher, &options);
+    tally.merge(part(100..200), 200).unwrap();
+    tally.merge(part(5..8), 8).unwrap();
+    let calibration = tally.finish();
+
+    assert_eq!(103, calibration.without_digits);
+    assert_eq!(
+        vec![5, 6, 7, 100, 101, 102, 103, 104, 105, 106],
+        calibration.first_without_digits
+    );
+    assert!(calibration
+        .to_string()
+        .ends_with(": 5, 6, 7, 100, 101, 102, 103, 104, 105, 106 and 93 more"));
+}
@@ -0,0 +1,93 @@
+use crate::err::ParseError;
+
+/// The tokens that count as digits on a calibration line, each paired with
+/// the digit it stands for.
+#[derive(Debug, Clone)]
+pub struct Vocabulary {
+    tokens: Vec<(String, i32)>,
+}
+
+const DIGITS: [(&str, i32); 9] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+const ENGLISH_WORDS: [(&str, i32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+impl Vocabulary {
+    /// Build a vocabulary from (token, value) pairs. Every value has to be
+    /// a single digit, since two of them make up a calibration value.
+    ///
+    /// A token that starts another token has to stand for the same digit,
+    /// otherwise there'd be no telling which of the two a line meant.
+    pub fn new<T: Into<String>>(
+        tokens: impl IntoIterator<Item = (T, i32)>,
+    ) -> Result<Self, ParseError> {
+        let tokens: Vec<(String, i32)> = tokens.into_iter().map(|(t, v)| (t.into(), v)).collect();
+        for (token, value) in tokens.iter() {
+            if token.is_empty() {
+                return Err(ParseError::new("Empty vocabulary token", token.clone()));
+            }
+            if !(0..=9).contains(value) {
+                return Err(ParseError::new(
+                    "Vocabulary value is not a digit",
+                    format!("{} = {}", token, value),
+                ));
+            }
+            if let Some((longer, _)) = tokens
+                .iter()
+                .find(|(other, v)| v != value && other.starts_with(token.as_str()))
+            {
+                return Err(ParseError::new(
+                    "Ambiguous vocabulary token",
+                    format!("{} / {}", token, longer),
+                ));
+            }
+        }
+        Ok(Vocabulary { tokens })
+    }
+
+    /// The digits 1 to 9 as written.
+    pub fn digits() -> Self {
+        Vocabulary::new(DIGITS).expect("digits are valid tokens")
+    }
+
+    /// The digits plus their spelled-out English names.
+    pub fn english() -> Self {
+        Vocabulary::new(DIGITS.into_iter().chain(ENGLISH_WORDS)).expect("words are valid tokens")
+    }
+
+    pub fn tokens(&self) -> impl Iterator<Item = (&str, i32)> {
+        self.tokens
+            .iter()
+            .map(|(token, value)| (token.as_str(), *value))
+    }
+}
+
+#[test]
+fn vocabularies_are_validated() {
+    assert!(Vocabulary::new([("eins", 1), ("zwei", 2), ("fünf", 5)]).is_ok());
+    assert!(Vocabulary::new([("one", 1), ("oneself", 1)]).is_ok());
+
+    assert!(Vocabulary::new([("ten", 10)]).is_err());
+    assert!(Vocabulary::new([("", 1)]).is_err());
+    assert!(Vocabulary::new([("six", 6), ("sixteen", 7)]).is_err());
+    assert!(Vocabulary::new([("six", 6), ("six", 7)]).is_err());
+}
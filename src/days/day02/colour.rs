@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::error;
+use std::fmt::{self, Debug, Display};
+use std::sync::{LazyLock, RwLock};
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A cube colour, interned so draws can count cubes by a small id instead
+/// of holding on to the text the colour was read from.
+///
+/// Ids are handed out by a process-wide registry in the order colours are
+/// first seen, so they're only meaningful within one run. A colour's name
+/// is kept for the rest of the process once it's registered, which is
+/// what lets [`Colour::name`] hand out a `&'static str`. Wherever colours
+/// are shown or written out they go by name, and in name order. The
+/// puzzle's own colours are registered up front.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour(u16);
+
+/// The most colours the registry will hold, which also bounds how much
+/// memory the names kept for the process can take up.
+const CAPACITY: usize = u16::MAX as usize + 1;
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| {
+    let mut registry = Registry::default();
+    for name in ["red", "green", "blue"] {
+        registry
+            .intern(name, CAPACITY)
+            .expect("room for the puzzle's colours");
+    }
+    RwLock::new(registry)
+});
+
+/// Names of every colour seen so far, indexed by id, and the other way
+/// round.
+#[derive(Default)]
+struct Registry {
+    names: Vec<&'static str>,
+    ids: HashMap<&'static str, Colour>,
+}
+
+impl Colour {
+    pub const RED: Colour = Colour(0);
+    pub const GREEN: Colour = Colour(1);
+    pub const BLUE: Colour = Colour(2);
+
+    /// The colour called `name`, registering it the first time it's seen.
+    pub fn named(name: &str) -> Result<Self, TooManyColours> {
+        if let Some(colour) = Self::lookup(name) {
+            return Ok(colour);
+        }
+        let mut registry = REGISTRY.write().expect("colour registry poisoned");
+        registry.intern(name, CAPACITY)
+    }
+
+    /// The colour called `name`, if it's been seen before.
+    pub fn lookup(name: &str) -> Option<Self> {
+        let registry = REGISTRY.read().expect("colour registry poisoned");
+        registry.ids.get(name).copied()
+    }
+
+    pub fn name(self) -> &'static str {
+        REGISTRY.read().expect("colour registry poisoned").names[self.index()]
+    }
+
+    /// Dense index of the colour, for storing counts in a vector.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub(super) fn from_index(index: usize) -> Self {
+        Colour(index as u16)
+    }
+}
+
+impl Registry {
+    /// Find or add `name`, as long as there's room for it. A new name is
+    /// leaked so it lives as long as the registry.
+    fn intern(&mut self, name: &str, capacity: usize) -> Result<Colour, TooManyColours> {
+        // someone else may have added it between the read and write locks
+        if let Some(&colour) = self.ids.get(name) {
+            return Ok(colour);
+        }
+        if self.names.len() >= capacity {
+            return Err(TooManyColours(name.to_owned()));
+        }
+        let colour = Colour(self.names.len() as u16);
+        let name: &'static str = Box::leak(name.into());
+        self.names.push(name);
+        self.ids.insert(name, colour);
+        Ok(colour)
+    }
+}
+
+/// A new colour was seen once the registry was already full.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TooManyColours(String);
+
+impl error::Error for TooManyColours {}
+
+impl Display for TooManyColours {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no room for another colour, {:?}", self.0)
+    }
+}
+
+impl Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Debug for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.name())
+    }
+}
+
+impl Serialize for Colour {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for Colour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Colour::named(&name).map_err(D::Error::custom)
+    }
+}
+
+#[test]
+fn colours_are_interned() {
+    let teal = Colour::named("teal").unwrap();
+
+    assert_eq!(teal, Colour::named("teal").unwrap());
+    assert_eq!(Some(teal), Colour::lookup("teal"));
+    assert_ne!(teal, Colour::named("mauve").unwrap());
+    assert_eq!("teal", teal.name());
+    assert_eq!(None, Colour::lookup("never-seen"));
+    assert_eq!(Some(Colour::GREEN), Colour::lookup("green"));
+}
+
+#[test]
+fn full_registries_turn_new_colours_away() {
+    let mut registry = Registry::default();
+    registry.intern("red", 2).unwrap();
+    registry.intern("green", 2).unwrap();
+
+    assert_eq!(Ok(Colour(1)), registry.intern("green", 2));
+    assert_eq!(
+        Err(TooManyColours("blue".to_owned())),
+        registry.intern("blue", 2)
+    );
+    assert_eq!(Ok(Colour(2)), registry.intern("blue", 3));
+}
@@ -0,0 +1,231 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::ops::{Add, Sub};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::Colour;
+
+/// Counts of cubes by colour, read as a multiset: a colour that isn't
+/// mentioned has a count of zero. Counts are kept in a vector indexed by
+/// [`Colour::index`], with trailing zeros trimmed, so two draws are equal
+/// exactly when they hold the same cubes. They're shown and written out
+/// by colour name.
+///
+/// Draws are partially ordered by inclusion. `a <= b` (or
+/// [`Draw::is_subset_of`]) when `a` has no more of any colour than `b`,
+/// i.e. `a` could have been drawn from a bag holding `b`. Draws with more
+/// of one colour and less of another aren't comparable. [`Draw::union`]
+/// and [`Draw::intersection`] are the least upper and greatest lower
+/// bounds of this order.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Draw(Vec<usize>);
+
+impl Draw {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how many cubes of `colour` the draw holds.
+    pub fn set(&mut self, colour: Colour, count: usize) {
+        let at = colour.index();
+        if at >= self.0.len() {
+            if count == 0 {
+                return;
+            }
+            self.0.resize(at + 1, 0);
+        }
+        self.0[at] = count;
+        self.trim();
+    }
+
+    /// How many cubes of `colour` the draw holds.
+    pub fn count(&self, colour: Colour) -> usize {
+        self.0.get(colour.index()).copied().unwrap_or(0)
+    }
+
+    /// Every colour in the draw with its count, in name order, skipping
+    /// colours it has none of.
+    pub fn colours(&self) -> impl Iterator<Item = (Colour, usize)> {
+        let mut colours: Vec<(Colour, usize)> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(at, &count)| (Colour::from_index(at), count))
+            .collect();
+        colours.sort_by_cached_key(|(colour, _)| colour.name());
+        colours.into_iter()
+    }
+
+    /// The counts of each of `colours` multiplied together, so a draw
+    /// missing any of them has no power at all. Nothing if the product is
+    /// too large for a `usize`.
+    pub fn power(&self, colours: &[Colour]) -> Option<usize> {
+        colours.iter().try_fold(1usize, |product, &colour| {
+            product.checked_mul(self.count(colour))
+        })
+    }
+
+    /// How many cubes the draw holds, of every colour, stopping at
+    /// `usize::MAX` if there are more than that.
+    pub fn total(&self) -> usize {
+        self.0
+            .iter()
+            .fold(0, |total, &count| total.saturating_add(count))
+    }
+
+    /// Whether this draw has no more of any colour than `other`.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        self.0.len() <= other.0.len() && self.0.iter().zip(other.0.iter()).all(|(a, b)| a <= b)
+    }
+
+    /// The most of each colour in either draw: the smallest bag both
+    /// could have come from.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, usize::max)
+    }
+
+    /// The least of each colour in both draws.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, usize::min)
+    }
+
+    fn combine(&self, other: &Self, f: impl Fn(usize, usize) -> usize) -> Self {
+        let len = self.0.len().max(other.0.len());
+        let count = |draw: &Self, at| draw.0.get(at).copied().unwrap_or(0);
+        let mut combined = Draw(
+            (0..len)
+                .map(|at| f(count(self, at), count(other, at)))
+                .collect(),
+        );
+        combined.trim();
+        combined
+    }
+
+    fn trim(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+}
+
+impl FromIterator<(Colour, usize)> for Draw {
+    fn from_iter<I: IntoIterator<Item = (Colour, usize)>>(iter: I) -> Self {
+        let mut draw = Draw::new();
+        for (colour, count) in iter {
+            draw.set(colour, count);
+        }
+        draw
+    }
+}
+
+impl Debug for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.colours()).finish()
+    }
+}
+
+/// Written as a map of colour names to counts.
+impl Serialize for Draw {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.colours())
+    }
+}
+
+impl<'de> Deserialize<'de> for Draw {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(HashMap::<Colour, usize>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
+
+impl PartialOrd for Draw {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self.is_subset_of(other), other.is_subset_of(self)) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+/// All the cubes of both draws, stopping at `usize::MAX` for any colour
+/// there'd be more of than that.
+impl Add for &Draw {
+    type Output = Draw;
+
+    fn add(self, other: Self) -> Draw {
+        self.combine(other, usize::saturating_add)
+    }
+}
+
+/// The cubes left after taking `other` away, stopping at zero for any
+/// colour `other` has more of.
+impl Sub for &Draw {
+    type Output = Draw;
+
+    fn sub(self, other: Self) -> Draw {
+        self.combine(other, usize::saturating_sub)
+    }
+}
+
+#[cfg(test)]
+pub(super) fn any_draw() -> impl proptest::strategy::Strategy<Value = Draw> {
+    use proptest::prelude::*;
+
+    proptest::collection::vec(0..20usize, 4).prop_map(|counts| {
+        ["red", "green", "blue", "pink"]
+            .into_iter()
+            .map(|name| Colour::named(name).unwrap())
+            .zip(counts)
+            .collect()
+    })
+}
+
+#[test]
+fn sums_stop_at_the_largest_count() {
+    let many: Draw = [(Colour::RED, usize::MAX), (Colour::BLUE, 1)]
+        .into_iter()
+        .collect();
+
+    assert_eq!(usize::MAX, many.total());
+    assert_eq!(usize::MAX, (&many + &many).count(Colour::RED));
+    assert_eq!(2, (&many + &many).count(Colour::BLUE));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn draws_form_a_lattice(a in any_draw(), b in any_draw(), c in any_draw()) {
+        // commutative, associative, idempotent, absorbing
+        proptest::prop_assert_eq!(a.union(&b), b.union(&a));
+        proptest::prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        proptest::prop_assert_eq!(a.union(&b).union(&c), a.union(&b.union(&c)));
+        proptest::prop_assert_eq!(
+            a.intersection(&b).intersection(&c),
+            a.intersection(&b.intersection(&c))
+        );
+        proptest::prop_assert_eq!(a.union(&a), a.clone());
+        proptest::prop_assert_eq!(a.intersection(&a), a.clone());
+        proptest::prop_assert_eq!(a.union(&a.intersection(&b)), a.clone());
+        proptest::prop_assert_eq!(a.intersection(&a.union(&b)), a.clone());
+
+        // the operations agree with the order
+        proptest::prop_assert!(a <= a.union(&b) && b <= a.union(&b));
+        proptest::prop_assert!(a.intersection(&b) <= a && a.intersection(&b) <= b);
+        proptest::prop_assert_eq!(a <= b, a.union(&b) == b);
+        proptest::prop_assert_eq!(a <= b, a.intersection(&b) == a);
+        if a <= b && b <= a {
+            proptest::prop_assert_eq!(&a, &b);
+        }
+
+        // adding cubes can be undone, and only makes a draw bigger
+        proptest::prop_assert_eq!(&(&a + &b) - &b, a.clone());
+        proptest::prop_assert!(a <= &a + &b);
+        proptest::prop_assert!(&a - &b <= a);
+    }
+}
@@ -0,0 +1,341 @@
+use std::error;
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+use crate::trace;
+
+mod colour;
+mod draw;
+mod query;
+mod search;
+
+pub use colour::{Colour, TooManyColours};
+pub use draw::Draw;
+pub use query::Query;
+pub use search::{best_bag, smallest_bag, Fit};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Game>;
+
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Game>, SolutionError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.is_empty())
+            .map(|(idx, game_str)| {
+                parse_game(game_str).map_err(|e| {
+                    ParseError::new("Invalid game", game_str)
+                        .at_line(idx + 1)
+                        .caused_by(e)
+                        .into()
+                })
+            })
+            .collect()
+    }
+
+    /// Sum of the ids of games that could have been played with a bag
+    /// holding 12 red, 13 green and 14 blue cubes.
+    fn part_one(games: &Vec<Game>) -> Result<usize, SolutionError> {
+        let bag: Draw = puzzle_colours().into_iter().zip([12, 13, 14]).collect();
+
+        solve(games, &bag)
+    }
+
+    /// Sum of the powers of the smallest bag each game could have been
+    /// played with.
+    fn part_two(games: &Vec<Game>) -> Result<usize, SolutionError> {
+        let colours = puzzle_colours();
+        games.iter().try_fold(0usize, |sum, game| {
+            let bag = game.minimum_bag();
+            let power = bag
+                .power(&colours)
+                .ok_or_else(|| overflowed("Power of minimum bag overflowed", game))?;
+            trace!(
+                "day02",
+                Info,
+                "game {}: minimum bag {:?}, power {}",
+                game.id,
+                bag,
+                power
+            );
+            sum.checked_add(power)
+                .ok_or_else(|| overflowed("Sum of powers overflowed", game))
+        })
+    }
+}
+
+/// The colours the puzzle's bag holds, which a bag's power is measured
+/// against.
+pub fn puzzle_colours() -> [Colour; 3] {
+    [Colour::RED, Colour::GREEN, Colour::BLUE]
+}
+
+fn solve(games: &[Game], draw: &Draw) -> Result<usize, SolutionError> {
+    games.iter().try_fold(0usize, |acc, game| {
+        if game.could_support(draw) {
+            let sum = acc
+                .checked_add(game.id)
+                .ok_or_else(|| overflowed("Sum of ids overflowed", game))?;
+            trace!("day02", Info, "game {} possible, sum {}", game.id, sum);
+            Ok(sum)
+        } else {
+            trace!("day02", Info, "game {} impossible", game.id);
+            Ok(acc)
+        }
+    })
+}
+
+/// A total that no longer fits, named by the game that tipped it over.
+fn overflowed(message: &'static str, game: &Game) -> SolutionError {
+    ParseError::new(message, format!("Game {}", game.id)).into()
+}
+
+/// One game's id and the draws seen in it. Owns its draws, so games can
+/// outlive the text they were parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Game {
+    id: usize,
+    draws: Vec<Draw>,
+}
+
+impl Game {
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn draws(&self) -> &[Draw] {
+        &self.draws
+    }
+
+    /// The fewest cubes of each colour the bag could have held: as many as
+    /// were ever drawn at once.
+    pub fn minimum_bag(&self) -> Draw {
+        self.draws
+            .iter()
+            .fold(Draw::new(), |bag, draw| bag.union(draw))
+    }
+
+    /// Whether every draw in the game could have come out of `bag`.
+    fn could_support(&self, bag: &Draw) -> bool {
+        self.draws.iter().all(|d| {
+            let supported = d.is_subset_of(bag);
+            trace!(
+                "day02",
+                Trace,
+                "game {}: {:?} fits: {}",
+                self.id,
+                d,
+                supported
+            );
+            supported
+        })
+    }
+}
+
+fn parse_game(s: &str) -> Result<Game, GameParseError> {
+    let mut parts = s.split(": ");
+    if let Some((game_header, draws)) = parts.next().zip(parts.next()) {
+        let mut parts = game_header.split(' ');
+        if let Some((_, id)) = parts.next().zip(parts.next()) {
+            let draws = draws
+                .split("; ")
+                .map(parse_draw)
+                .collect::<Result<_, _>>()
+                .map_err(GameParseError::InvalidDraws)?;
+
+            let id = str::parse(id).map_err(|_| GameParseError::InvalidId)?;
+
+            Ok(Game { id, draws })
+        } else {
+            Err(GameParseError::InvalidGameHeader)
+        }
+    } else {
+        Err(GameParseError::InvalidGameString)
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
+enum GameParseError {
+    InvalidGameString,
+    InvalidGameHeader,
+    InvalidId,
+    InvalidDraws(DrawParseError),
+}
+
+impl error::Error for GameParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidDraws(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Display for GameParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "failed to parse game: {}",
+            match self {
+                Self::InvalidGameString => "malformed game string".to_owned(),
+                Self::InvalidGameHeader => "malformed game header".to_owned(),
+                Self::InvalidId => "invalid game ID string".to_owned(),
+                Self::InvalidDraws(_) => "invalid draws".to_owned(),
+            }
+        )
+    }
+}
+
+fn parse_draw(s: &str) -> Result<Draw, DrawParseError> {
+    s.split(r", ").try_fold(Draw::new(), |mut draw, draw_str| {
+        let parts: Vec<&str> = draw_str.split(' ').collect();
+
+        if let Some((count_str, color)) = parts.first().zip(parts.get(1)) {
+            let count = str::parse(count_str).map_err(|_| DrawParseError::InvalidCount)?;
+            let colour = Colour::named(color).map_err(DrawParseError::TooManyColours)?;
+            draw.set(colour, count);
+            Ok(draw)
+        } else {
+            Err(DrawParseError::InvalidColorCountPair)
+        }
+    })
+}
+
+#[derive(Debug)]
+enum DrawParseError {
+    InvalidColorCountPair,
+    InvalidCount,
+    TooManyColours(TooManyColours),
+}
+
+impl error::Error for DrawParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::TooManyColours(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl Display for DrawParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Draw parsing failed: {}",
+            match self {
+                Self::InvalidColorCountPair => "invalid color count pair",
+                Self::InvalidCount => "invalid count",
+                Self::TooManyColours(_) => "too many colours",
+            }
+        )
+    }
+}
+
+#[test]
+fn full_input() {
+    let parsed = Day02::parse(include_str!("../../../fixtures/day-02.txt")).unwrap();
+    assert_eq!(2105, Day02::part_one(&parsed).unwrap());
+    assert_eq!(72422, Day02::part_two(&parsed).unwrap());
+}
+
+#[test]
+fn minimum_bag_takes_the_most_of_each_colour() {
+    let game =
+        parse_game("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")
+            .unwrap();
+    let bag = game.minimum_bag();
+
+    assert_eq!(
+        (20, 13, 6),
+        (
+            bag.count(Colour::RED),
+            bag.count(Colour::GREEN),
+            bag.count(Colour::BLUE)
+        )
+    );
+    assert_eq!(Some(1560), bag.power(&puzzle_colours()));
+}
+
+#[test]
+fn games_missing_a_colour_have_no_power() {
+    let parsed = Day02::parse("Game 1: 3 blue, 4 red\nGame 2: 1 red; 2 green, 1 blue\n").unwrap();
+    assert_eq!(2, Day02::part_two(&parsed).unwrap());
+    assert_eq!(Some(0), Draw::new().power(&puzzle_colours()));
+}
+
+#[test]
+fn overflowing_totals_are_errors() {
+    let message = |result: Result<usize, SolutionError>| match result.unwrap_err() {
+        SolutionError::ParseError(e) => (e.message(), e.text().to_owned()),
+        e => panic!("unexpected error {:?}", e),
+    };
+
+    let games =
+        Day02::parse("Game 1: 10000000000 red, 10000000000 green, 10000000000 blue").unwrap();
+    assert_eq!(
+        ("Power of minimum bag overflowed", "Game 1".to_owned()),
+        message(Day02::part_two(&games))
+    );
+
+    let half = usize::MAX / 2 + 1;
+    let games = Day02::parse(&format!(
+        "Game 1: {half} red, 1 green, 1 blue\nGame 2: {half} red, 1 green, 1 blue\n"
+    ))
+    .unwrap();
+    assert_eq!(
+        ("Sum of powers overflowed", "Game 2".to_owned()),
+        message(Day02::part_two(&games))
+    );
+
+    let games = Day02::parse(&format!("Game {}: 1 red\nGame 2: 1 red\n", usize::MAX)).unwrap();
+    assert_eq!(
+        ("Sum of ids overflowed", "Game 2".to_owned()),
+        message(Day02::part_one(&games))
+    );
+}
+
+#[test]
+fn draws_are_ordered_by_inclusion() {
+    let draw = |s| parse_draw(s).unwrap();
+    let bag = draw("12 red, 13 green, 14 blue");
+
+    assert!(draw("3 blue, 4 red") <= bag);
+    assert!(draw("1 red, 0 pink") < bag);
+    assert!(!draw("20 red").is_subset_of(&bag));
+    assert!(!draw("1 pink").is_subset_of(&bag));
+    assert_eq!(None, draw("20 red").partial_cmp(&draw("1 blue")));
+    assert_eq!(draw("2 red, 0 blue"), draw("2 red"));
+
+    assert_eq!(
+        draw("4 red, 2 blue"),
+        &(&draw("4 red, 5 blue") - &draw("1 red, 3 blue")) + &draw("1 red")
+    );
+}
+
+#[test]
+fn games_stand_alone() {
+    fn owned<T: Send + Sync + 'static>(_: &T) {}
+
+    let game = {
+        let text = String::from("Game 7: 3 blue, 4 red; 1 red, 2 green, 6 blue");
+        parse_game(&text).unwrap()
+    };
+    owned(&game);
+
+    let json = serde_json::to_string(&game).unwrap();
+    assert_eq!(
+        r#"{"id":7,"draws":[{"blue":3,"red":4},{"blue":6,"green":2,"red":1}]}"#,
+        json
+    );
+    assert_eq!(game, serde_json::from_str::<Game>(&json).unwrap());
+}
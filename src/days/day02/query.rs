@@ -0,0 +1,617 @@
+//! Questions about cube games, asked as short expressions:
+//!
+//! ```text
+//! games where max(red) > 15
+//! games where draws <= 3 and not power < 100
+//! sum(blue), count where id > 50 or (max(red) > 12 and max(green) > 12)
+//! ```
+//!
+//! A query is either `games`, listing the matching games, or a comma
+//! separated list of aggregates (`count`, `sum(..)`, `min(..)`,
+//! `max(..)`) over them, optionally followed by `where` and a condition.
+//!
+//! Conditions compare a per-game metric with a number (`<`, `<=`, `>`,
+//! `>=`, `=`, `!=`) and combine with `and`, `or`, `not` and parentheses.
+//! The metrics are `id`, `draws` (how many draws the game had), `power`
+//! (of its minimum bag, over red, green and blue), `max(colour)` and
+//! `min(colour)` (the most and fewest of a colour in any one draw) and
+//! `total(colour)`, or just the colour's name, for how many were seen
+//! across all its draws. A value too large to count is shown as
+//! `overflow` and is greater than any number.
+//!
+//! Queries are read against the games they're about: only colours drawn in
+//! some game can be asked about, and any other name is reported as an
+//! unknown colour.
+
+use std::collections::HashSet;
+use std::fmt::{self, Display};
+
+use crate::err::ParseError;
+use crate::table::Table;
+
+use super::{puzzle_colours, Colour, Game};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    output: Output,
+    condition: Option<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Output {
+    Games,
+    Aggregates(Vec<Aggregate>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Aggregate {
+    Count,
+    Sum(Metric),
+    Min(Metric),
+    Max(Metric),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Metric {
+    Id,
+    Draws,
+    Power,
+    Max(Colour),
+    Min(Colour),
+    Total(Colour),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Condition {
+    Compare(Metric, Comparison, usize),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Query {
+    /// Read a query about `games`, which decide the colours it can name.
+    pub fn parse(text: &str, games: &[Game]) -> Result<Self, ParseError> {
+        let mut parser = Parser {
+            text,
+            tokens: tokenize(text)?,
+            at: 0,
+            colours: games
+                .iter()
+                .flat_map(Game::draws)
+                .flat_map(|draw| draw.colours().map(|(colour, _)| colour))
+                .collect(),
+        };
+        let query = parser.query()?;
+        match parser.peek() {
+            None => Ok(query),
+            Some(token) => Err(parser.error("Unexpected text after query", token)),
+        }
+    }
+
+    /// The games the query's condition picks out, in order.
+    pub fn matching<'g>(&self, games: &'g [Game]) -> Vec<&'g Game> {
+        games
+            .iter()
+            .filter(|game| self.condition.as_ref().is_none_or(|c| c.holds(game)))
+            .collect()
+    }
+
+    /// Answer the query as a table: one row per matching game for `games`,
+    /// or a single row with a column per aggregate.
+    pub fn run(&self, games: &[Game]) -> Table {
+        let games = self.matching(games);
+        match &self.output {
+            Output::Games => {
+                let mut table = Table::new(["id", "draws", "minimum bag", "power"]);
+                for game in games {
+                    let bag = game.minimum_bag();
+                    let cubes: Vec<String> = bag
+                        .colours()
+                        .map(|(colour, count)| format!("{} {}", count, colour))
+                        .collect();
+                    table.row([
+                        game.id().to_string(),
+                        game.draws().len().to_string(),
+                        cubes.join(", "),
+                        show(bag.power(&puzzle_colours())),
+                    ]);
+                }
+                table
+            }
+            Output::Aggregates(aggregates) => {
+                let mut table = Table::new(aggregates.iter().map(Aggregate::to_string));
+                table.row(
+                    aggregates
+                        .iter()
+                        .map(|aggregate| aggregate.over(&games).map_or("-".to_owned(), show)),
+                );
+                table
+            }
+        }
+    }
+}
+
+/// A metric's value, or nothing if it's too large for a `usize`.
+type Value = Option<usize>;
+
+fn show(value: Value) -> String {
+    value.map_or("overflow".to_owned(), |v| v.to_string())
+}
+
+/// Orders values with the ones too large to count last.
+fn by_size(value: &Value) -> (bool, Value) {
+    (value.is_none(), *value)
+}
+
+impl Aggregate {
+    /// Nothing for the min or max of no games.
+    fn over(self, games: &[&Game]) -> Option<Value> {
+        let values = |metric: Metric| games.iter().map(move |game| metric.of(game));
+        match self {
+            Aggregate::Count => Some(Some(games.len())),
+            Aggregate::Sum(metric) => {
+                Some(values(metric).try_fold(0, |sum: usize, v| sum.checked_add(v?)))
+            }
+            Aggregate::Min(metric) => values(metric).min_by_key(by_size),
+            Aggregate::Max(metric) => values(metric).max_by_key(by_size),
+        }
+    }
+}
+
+impl Metric {
+    fn of(self, game: &Game) -> Value {
+        let counts = |colour| game.draws().iter().map(move |draw| draw.count(colour));
+        match self {
+            Metric::Id => Some(game.id()),
+            Metric::Draws => Some(game.draws().len()),
+            Metric::Power => game.minimum_bag().power(&puzzle_colours()),
+            Metric::Max(colour) => Some(counts(colour).max().unwrap_or(0)),
+            Metric::Min(colour) => Some(counts(colour).min().unwrap_or(0)),
+            Metric::Total(colour) => counts(colour).try_fold(0, usize::checked_add),
+        }
+    }
+}
+
+impl Condition {
+    fn holds(&self, game: &Game) -> bool {
+        match self {
+            Condition::Compare(metric, comparison, value) => match metric.of(game) {
+                Some(actual) => match comparison {
+                    Comparison::Less => actual < *value,
+                    Comparison::LessOrEqual => actual <= *value,
+                    Comparison::Greater => actual > *value,
+                    Comparison::GreaterOrEqual => actual >= *value,
+                    Comparison::Equal => actual == *value,
+                    Comparison::NotEqual => actual != *value,
+                },
+                // too large to count, so more than any number in a query
+                None => matches!(
+                    comparison,
+                    Comparison::Greater | Comparison::GreaterOrEqual | Comparison::NotEqual
+                ),
+            },
+            Condition::Not(condition) => !condition.holds(game),
+            Condition::And(a, b) => a.holds(game) && b.holds(game),
+            Condition::Or(a, b) => a.holds(game) || b.holds(game),
+        }
+    }
+}
+
+impl Display for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Aggregate::Count => f.write_str("count"),
+            Aggregate::Sum(metric) => write!(f, "sum({})", metric),
+            Aggregate::Min(metric) => write!(f, "min({})", metric),
+            Aggregate::Max(metric) => write!(f, "max({})", metric),
+        }
+    }
+}
+
+impl Display for Metric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Metric::Id => f.write_str("id"),
+            Metric::Draws => f.write_str("draws"),
+            Metric::Power => f.write_str("power"),
+            Metric::Max(colour) => write!(f, "max({})", colour),
+            Metric::Min(colour) => write!(f, "min({})", colour),
+            Metric::Total(colour) => write!(f, "{}", colour),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Kind {
+    Word(String),
+    Number(usize),
+    Compare(Comparison),
+    Open,
+    Close,
+    Comma,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    kind: Kind,
+    /// Byte offsets in the query text.
+    start: usize,
+    end: usize,
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = vec![];
+    let mut chars = text.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let mut take_while = |first: usize, f: fn(char) -> bool| {
+            let mut end = first;
+            while let Some(&(at, c)) = chars.peek() {
+                if !f(c) {
+                    break;
+                }
+                end = at + c.len_utf8();
+                chars.next();
+            }
+            end
+        };
+        let (kind, end) = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => (Kind::Open, start + 1),
+            ')' => (Kind::Close, start + 1),
+            ',' => (Kind::Comma, start + 1),
+            '0'..='9' => {
+                let end = take_while(start + 1, |c| c.is_ascii_digit());
+                let number = text[start..end].parse().map_err(|e| {
+                    ParseError::new("Number too large", &text[start..end])
+                        .at_column(start + 1)
+                        .caused_by(e)
+                })?;
+                (Kind::Number(number), end)
+            }
+            '<' | '>' | '=' | '!' => {
+                let end = take_while(start + 1, |c| c == '=');
+                let comparison = match &text[start..end] {
+                    "<" => Comparison::Less,
+                    "<=" => Comparison::LessOrEqual,
+                    ">" => Comparison::Greater,
+                    ">=" => Comparison::GreaterOrEqual,
+                    "=" | "==" => Comparison::Equal,
+                    "!=" => Comparison::NotEqual,
+                    other => {
+                        return Err(
+                            ParseError::new("Unknown comparison", other).at_column(start + 1)
+                        )
+                    }
+                };
+                (Kind::Compare(comparison), end)
+            }
+            _ if c.is_alphabetic() => {
+                let end = take_while(start + c.len_utf8(), |c| {
+                    c.is_alphanumeric() || c == '_' || c == '-'
+                });
+                (Kind::Word(text[start..end].to_lowercase()), end)
+            }
+            _ => {
+                return Err(
+                    ParseError::new("Unexpected character", c.to_string()).at_column(start + 1)
+                )
+            }
+        };
+        tokens.push(Token { kind, start, end });
+    }
+    Ok(tokens)
+}
+
+struct Parser<'t> {
+    text: &'t str,
+    tokens: Vec<Token>,
+    at: usize,
+    /// Every colour drawn in the games being queried.
+    colours: HashSet<Colour>,
+}
+
+impl<'t> Parser<'t> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.at)
+    }
+
+    fn next(&mut self) -> Result<Token, ParseError> {
+        let token = self.tokens.get(self.at).cloned().ok_or_else(|| {
+            ParseError::new("Query ends too soon", self.text).at_column(self.text.len() + 1)
+        })?;
+        self.at += 1;
+        Ok(token)
+    }
+
+    fn error(&self, message: &'static str, token: &Token) -> ParseError {
+        ParseError::new(message, &self.text[token.start..token.end]).at_column(token.start + 1)
+    }
+
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token { kind: Kind::Word(w), .. }) if w == word)
+    }
+
+    fn expect(&mut self, kind: Kind, message: &'static str) -> Result<(), ParseError> {
+        let token = self.next()?;
+        if token.kind == kind {
+            Ok(())
+        } else {
+            Err(self.error(message, &token))
+        }
+    }
+
+    fn query(&mut self) -> Result<Query, ParseError> {
+        let output = if self.is_word("games") {
+            self.at += 1;
+            Output::Games
+        } else {
+            let mut aggregates = vec![self.aggregate()?];
+            while self.peek().is_some_and(|t| t.kind == Kind::Comma) {
+                self.at += 1;
+                aggregates.push(self.aggregate()?);
+            }
+            Output::Aggregates(aggregates)
+        };
+
+        let condition = if self.is_word("where") {
+            self.at += 1;
+            Some(self.or()?)
+        } else {
+            None
+        };
+
+        Ok(Query { output, condition })
+    }
+
+    fn aggregate(&mut self) -> Result<Aggregate, ParseError> {
+        let token = self.next()?;
+        let Kind::Word(word) = &token.kind else {
+            return Err(self.error("Expected games, count, sum, min or max", &token));
+        };
+        let wrap: fn(Metric) -> Aggregate = match word.as_str() {
+            "count" => return Ok(Aggregate::Count),
+            "sum" => Aggregate::Sum,
+            "min" => Aggregate::Min,
+            "max" => Aggregate::Max,
+            _ => return Err(self.error("Expected games, count, sum, min or max", &token)),
+        };
+        self.expect(Kind::Open, "Expected ( after aggregate")?;
+        let metric = self.metric()?;
+        self.expect(Kind::Close, "Expected ) to close aggregate")?;
+        Ok(wrap(metric))
+    }
+
+    fn metric(&mut self) -> Result<Metric, ParseError> {
+        let token = self.next()?;
+        let Kind::Word(word) = &token.kind else {
+            return Err(self.error("Expected a metric", &token));
+        };
+        let per_colour: fn(Colour) -> Metric = match word.as_str() {
+            "id" => return Ok(Metric::Id),
+            "draws" => return Ok(Metric::Draws),
+            "power" => return Ok(Metric::Power),
+            "max" => Metric::Max,
+            "min" => Metric::Min,
+            "total" => Metric::Total,
+            _ => return Ok(Metric::Total(self.colour(&token)?)),
+        };
+        self.expect(Kind::Open, "Expected ( after metric")?;
+        let token = self.next()?;
+        let colour = self.colour(&token)?;
+        self.expect(Kind::Close, "Expected ) after colour")?;
+        Ok(per_colour(colour))
+    }
+
+    /// A colour drawn in one of the games, since any other name is a typo.
+    fn colour(&self, token: &Token) -> Result<Colour, ParseError> {
+        let Kind::Word(name) = &token.kind else {
+            return Err(self.error("Expected a colour", token));
+        };
+        Colour::lookup(name)
+            .filter(|colour| self.colours.contains(colour))
+            .ok_or_else(|| self.error("Unknown colour", token))
+    }
+
+    fn or(&mut self) -> Result<Condition, ParseError> {
+        let mut condition = self.and()?;
+        while self.is_word("or") {
+            self.at += 1;
+            condition = Condition::Or(Box::new(condition), Box::new(self.and()?));
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, ParseError> {
+        let mut condition = self.not()?;
+        while self.is_word("and") {
+            self.at += 1;
+            condition = Condition::And(Box::new(condition), Box::new(self.not()?));
+        }
+        Ok(condition)
+    }
+
+    fn not(&mut self) -> Result<Condition, ParseError> {
+        if self.is_word("not") {
+            self.at += 1;
+            return Ok(Condition::Not(Box::new(self.not()?)));
+        }
+        if self.peek().is_some_and(|t| t.kind == Kind::Open) {
+            self.at += 1;
+            let condition = self.or()?;
+            self.expect(Kind::Close, "Expected ) to close condition")?;
+            return Ok(condition);
+        }
+
+        let metric = self.metric()?;
+        let token = self.next()?;
+        let Kind::Compare(comparison) = token.kind else {
+            return Err(self.error("Expected a comparison", &token));
+        };
+        let token = self.next()?;
+        let Kind::Number(value) = token.kind else {
+            return Err(self.error("Expected a number", &token));
+        };
+        Ok(Condition::Compare(metric, comparison, value))
+    }
+}
+
+#[cfg(test)]
+pub(super) fn example_games() -> Vec<Game> {
+    use crate::solution::Solution;
+
+    super::Day02::parse(
+        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    )
+    .unwrap()
+}
+
+#[test]
+fn filters_games() {
+    let games = example_games();
+    let ids = |query: &str| -> Vec<usize> {
+        Query::parse(query, &games)
+            .unwrap()
+            .matching(&games)
+            .iter()
+            .map(|g| g.id())
+            .collect()
+    };
+
+    assert_eq!(vec![3], ids("games where max(red) > 15"));
+    assert_eq!(vec![1, 2, 3, 4, 5], ids("games where draws <= 3"));
+    assert_eq!(vec![5], ids("games where draws = 2"));
+    assert_eq!(
+        vec![1, 2, 5],
+        ids("games where not (max(red) > 12 or max(blue) > 14)")
+    );
+    assert_eq!(
+        vec![3, 4],
+        ids("games where power >= 630 and min(green) != 5 or id = 3")
+    );
+}
+
+#[test]
+fn aggregates_games() {
+    let games = example_games();
+    let table = Query::parse(
+        "sum(blue), count, max(power), min(id) where max(red) > 100",
+        &games,
+    )
+    .unwrap()
+    .run(&games);
+
+    assert_eq!(
+        "sum(blue)  count  max(power)  min(id)\n---------  -----  ----------  -------\n0          0      -           -\n",
+        table.to_string()
+    );
+    assert_eq!(
+        "sum(blue)  sum(power)\n---------  ----------\n50         2286\n",
+        Query::parse("sum(total(blue)), sum(power)", &games)
+            .unwrap()
+            .run(&games)
+            .to_string()
+    );
+}
+
+#[test]
+fn overflowing_powers_are_larger_than_any_number() {
+    use crate::solution::Solution;
+
+    let games = super::Day02::parse(
+        "Game 1: 10000000000 red, 10000000000 green, 10000000000 blue\nGame 2: 1 red, 2 green, 3 blue",
+    )
+    .unwrap();
+    let ids = |query: &str| -> Vec<usize> {
+        Query::parse(query, &games)
+            .unwrap()
+            .matching(&games)
+            .iter()
+            .map(|g| g.id())
+            .collect()
+    };
+
+    assert_eq!(vec![1], ids("games where power > 1000"));
+    assert_eq!(vec![2], ids("games where power <= 1000"));
+    assert_eq!(
+        "max(power)  min(power)\n----------  ----------\noverflow    6\n",
+        Query::parse("max(power), min(power)", &games)
+            .unwrap()
+            .run(&games)
+            .to_string()
+    );
+    assert!(Query::parse("games", &games)
+        .unwrap()
+        .run(&games)
+        .to_string()
+        .contains("overflow"));
+}
+
+#[test]
+fn overflowing_sums_are_shown_as_overflow() {
+    use crate::solution::Solution;
+
+    let games = super::Day02::parse(&format!(
+        "Game 1: {} red\nGame 2: 5 red; 5 red\n",
+        usize::MAX
+    ))
+    .unwrap();
+    let answer = |query: &str| Query::parse(query, &games).unwrap().run(&games).to_string();
+
+    assert!(answer("sum(red)").ends_with("overflow\n"));
+    assert!(answer("sum(total(red)) where id = 2").ends_with("10\n"));
+
+    let games = super::Day02::parse(&format!("Game 1: {} red; 1 red\n", usize::MAX)).unwrap();
+    assert_eq!(
+        vec![1],
+        Query::parse("games where red > 5", &games)
+            .unwrap()
+            .matching(&games)
+            .iter()
+            .map(|g| g.id())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn errors_point_at_the_problem() {
+    let games = example_games();
+
+    let err = Query::parse("games where max(red) >> 3", &games).unwrap_err();
+    assert_eq!((Some(23), ">"), (err.column(), err.text()));
+
+    let err = Query::parse("games where max(red) > 3 sum", &games).unwrap_err();
+    assert_eq!((Some(26), "sum"), (err.column(), err.text()));
+
+    let err = Query::parse("games where rde > 3", &games).unwrap_err();
+    assert_eq!(
+        ("Unknown colour", Some(13), "rde"),
+        (err.message(), err.column(), err.text())
+    );
+    let err = Query::parse("sum(max(bleu))", &games).unwrap_err();
+    assert_eq!(("Unknown colour", Some(9)), (err.message(), err.column()));
+
+    // only the colours drawn in the games being queried count
+    Colour::named("teal").unwrap();
+    let err = Query::parse("games where teal > 0", &games).unwrap_err();
+    assert_eq!(("Unknown colour", "teal"), (err.message(), err.text()));
+    assert!(Query::parse("sum(red)", &[]).is_err());
+
+    assert!(Query::parse("average(red)", &games).is_err());
+    assert!(Query::parse("games where (draws > 1", &games).is_err());
+    assert!(Query::parse("games where", &games).is_err());
+}
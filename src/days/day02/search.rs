@@ -0,0 +1,280 @@
+//! Choosing a bag for a set of games.
+//!
+//! A game could have been played with a bag exactly when its minimum bag
+//! fits inside it, so only the minimum bags matter. A best bag never
+//! needs more of a colour than some game's minimum bag asks for, which
+//! leaves few counts worth trying per colour. The search picks a count
+//! for one colour at a time, most cubes first, keeping only the games
+//! that still fit. It abandons a branch as soon as the games left can't
+//! beat the best bag found so far.
+
+use std::fmt::{self, Display};
+
+use super::{Colour, Draw, Game};
+
+/// A bag and the games that could have been played with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fit {
+    pub bag: Draw,
+    /// Ids of the games the bag supports, in input order.
+    pub games: Vec<usize>,
+}
+
+impl Display for Fit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes: Vec<String> = self
+            .bag
+            .colours()
+            .map(|(colour, count)| format!("{} {}", count, colour))
+            .collect();
+        write!(
+            f,
+            "{} cubes ({}) support {} games",
+            self.bag.total(),
+            cubes.join(", "),
+            self.games.len()
+        )?;
+        if !self.games.is_empty() {
+            let ids: Vec<String> = self.games.iter().map(usize::to_string).collect();
+            write!(f, ": {}", ids.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// The bag of at most `budget` cubes that supports the most games, and of
+/// those the one with the fewest cubes.
+pub fn best_bag(games: &[Game], budget: usize) -> Fit {
+    Search::new(games).best(budget)
+}
+
+/// The bag with the fewest cubes that supports at least `k` games, or
+/// nothing if there aren't that many games or the bag would hold more
+/// than `usize::MAX` cubes.
+pub fn smallest_bag(games: &[Game], k: usize) -> Option<Fit> {
+    if k > games.len() {
+        return None;
+    }
+
+    // the most games a budget supports only grows with the budget, and the
+    // bag holding every minimum bag supports them all
+    let search = Search::new(games);
+    let (mut low, mut high) = (0, search.union_total());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if search.best(mid).games.len() >= k {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    // the union total stops at usize::MAX, so the bag found may still be
+    // too small
+    Some(search.best(low)).filter(|fit| fit.games.len() >= k)
+}
+
+struct Search<'g> {
+    games: &'g [Game],
+    colours: Vec<Colour>,
+    /// Each game's minimum bag, as counts in the order of `colours`.
+    needs: Vec<Vec<usize>>,
+}
+
+/// The best choice found so far: how many games it supports, its total
+/// cubes and the games themselves.
+struct Best {
+    supported: usize,
+    total: usize,
+    games: Vec<usize>,
+}
+
+impl<'g> Search<'g> {
+    fn new(games: &'g [Game]) -> Self {
+        let bags: Vec<Draw> = games.iter().map(Game::minimum_bag).collect();
+        let mut colours: Vec<Colour> = bags
+            .iter()
+            .flat_map(|bag| bag.colours().map(|(colour, _)| colour))
+            .collect();
+        colours.sort_by_key(|colour| colour.index());
+        colours.dedup();
+
+        let needs = bags
+            .iter()
+            .map(|bag| colours.iter().map(|&colour| bag.count(colour)).collect())
+            .collect();
+
+        Search {
+            games,
+            colours,
+            needs,
+        }
+    }
+
+    /// Cubes in the smallest bag supporting every game, stopping at
+    /// `usize::MAX`, which no budget can go past anyway.
+    fn union_total(&self) -> usize {
+        let everyone: Vec<usize> = (0..self.games.len()).collect();
+        self.total(&everyone)
+    }
+
+    fn best(&self, budget: usize) -> Fit {
+        let mut best = Best {
+            supported: 0,
+            total: 0,
+            games: vec![],
+        };
+        let everyone: Vec<usize> = (0..self.games.len()).collect();
+        self.choose(0, budget, everyone, &mut best);
+
+        // the smallest bag for the chosen games is the union of their
+        // minimum bags
+        let bag = self
+            .colours
+            .iter()
+            .enumerate()
+            .map(|(at, &colour)| {
+                let most = best.games.iter().map(|&g| self.needs[g][at]).max();
+                (colour, most.unwrap_or(0))
+            })
+            .collect();
+        Fit {
+            bag,
+            games: best.games.iter().map(|&g| self.games[g].id()).collect(),
+        }
+    }
+
+    /// Try every worthwhile count of `self.colours[at]` within `budget`,
+    /// given that only the games in `fitting` fit the counts chosen so far.
+    fn choose(&self, at: usize, budget: usize, fitting: Vec<usize>, best: &mut Best) {
+        if at == self.colours.len() {
+            let total = self.total(&fitting);
+            if fitting.len() > best.supported
+                || (fitting.len() == best.supported && total < best.total)
+            {
+                best.supported = fitting.len();
+                best.total = total;
+                best.games = fitting;
+            }
+            return;
+        }
+
+        let mut counts: Vec<usize> = fitting
+            .iter()
+            .map(|&g| self.needs[g][at])
+            .filter(|&count| count <= budget)
+            .collect();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.dedup();
+
+        for count in counts {
+            let still_fitting: Vec<usize> = fitting
+                .iter()
+                .copied()
+                .filter(|&g| self.needs[g][at] <= count)
+                .collect();
+            // fewer cubes of this colour can only lose more games
+            if still_fitting.len() < best.supported {
+                break;
+            }
+            self.choose(at + 1, budget - count, still_fitting, best);
+        }
+    }
+
+    /// Cubes in the smallest bag supporting all of `games`, stopping at
+    /// `usize::MAX` like [`Draw::total`].
+    fn total(&self, games: &[usize]) -> usize {
+        (0..self.colours.len())
+            .map(|at| games.iter().map(|&g| self.needs[g][at]).max().unwrap_or(0))
+            .fold(0, usize::saturating_add)
+    }
+}
+
+#[cfg(test)]
+use super::query::example_games;
+
+#[test]
+fn best_bags_within_budgets() {
+    let games = example_games();
+
+    let fit = best_bag(&games, 39);
+    assert_eq!(vec![1, 2, 4, 5], fit.games);
+    assert_eq!(32, fit.bag.total());
+    assert!(games
+        .iter()
+        .all(|game| game.could_support(&fit.bag) == fit.games.contains(&game.id())));
+
+    assert_eq!(Vec::<usize>::new(), best_bag(&games, 5).games);
+    assert_eq!(Vec::<usize>::new(), best_bag(&games, 7).games);
+    assert_eq!(vec![2], best_bag(&games, 8).games);
+    assert_eq!(5, best_bag(&games, 100).games.len());
+}
+
+#[test]
+fn smallest_bags_for_enough_games() {
+    let games = example_games();
+
+    assert_eq!(0, smallest_bag(&games, 0).unwrap().bag.total());
+    assert_eq!(vec![2], smallest_bag(&games, 1).unwrap().games);
+    assert_eq!(32, smallest_bag(&games, 4).unwrap().bag.total());
+    let everyone = smallest_bag(&games, 5).unwrap();
+    assert_eq!(48, everyone.bag.total());
+    assert_eq!(
+        "48 cubes (15 blue, 13 green, 20 red) support 5 games: 1, 2, 3, 4, 5",
+        everyone.to_string()
+    );
+    assert_eq!(None, smallest_bag(&games, 6));
+}
+
+#[test]
+fn huge_bags_are_searched_without_overflowing() {
+    use crate::solution::Solution;
+
+    let games = super::Day02::parse(&format!(
+        "Game 1: {max} red, {max} blue\nGame 2: 1 red, 1 green\n",
+        max = usize::MAX
+    ))
+    .unwrap();
+
+    assert_eq!(vec![2], best_bag(&games, 10).games);
+    assert_eq!(vec![2], best_bag(&games, usize::MAX).games);
+    assert_eq!(vec![2], smallest_bag(&games, 1).unwrap().games);
+    assert_eq!(None, smallest_bag(&games, 2));
+}
+
+#[cfg(test)]
+proptest::proptest! {
+    #[test]
+    fn search_matches_every_subset(
+        bags in proptest::collection::vec(super::draw::any_draw(), 0..8),
+        budget in 0..60usize,
+    ) {
+        let games: Vec<Game> = bags
+            .into_iter()
+            .enumerate()
+            .map(|(id, bag)| Game { id, draws: vec![bag] })
+            .collect();
+
+        // the bags worth trying are the unions of some of the games'
+        // minimum bags
+        let mut expected = (0, 0);
+        for subset in 0..1usize << games.len() {
+            let bag = games
+                .iter()
+                .enumerate()
+                .filter(|(at, _)| subset & (1 << at) != 0)
+                .fold(Draw::new(), |bag, (_, game)| bag.union(&game.minimum_bag()));
+            if bag.total() <= budget {
+                let supported = games.iter().filter(|g| g.could_support(&bag)).count();
+                if supported > expected.0 || (supported == expected.0 && bag.total() < expected.1) {
+                    expected = (supported, bag.total());
+                }
+            }
+        }
+
+        let fit = best_bag(&games, budget);
+        proptest::prop_assert_eq!(expected, (fit.games.len(), fit.bag.total()));
+        for game in &games {
+            proptest::prop_assert_eq!(game.could_support(&fit.bag), fit.games.contains(&game.id()));
+        }
+    }
+}
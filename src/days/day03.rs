@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+use crate::trace;
+
+/// This problem involves inspecting a 2D grid of data,
+/// finding numbers that are adjacent to symbols, and
+/// summing those numbers up.
+///
+/// Notes:
+/// - "empty" cells are `.`
+/// - "symbols" are anything but `.`, letters, digits and whitespace
+/// - numbers are horizontally-contiguous spans of digits
+/// - adjacency = within 1 cell vertically, horizontally, diagonally
+/// - a number is adjacent to a symbol if the symbol matches the above
+///   definition for _any cell_ in the number's span, e.g.:
+///
+///   ```text
+///   .123....
+///   ....*.10
+///   ...234..
+///   ```
+///
+///   `123` and `234` are both adjacent to `*`, but `10` isn't.
+///
+/// This solution builds an R*Tree spatial index of the part IDs and
+/// then queries that index for neighbors of each found symbol.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Schematic;
+
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Schematic, SolutionError> {
+        let symbols = find_symbols(input);
+        let part_index = build_part_index(input)?;
+        trace!("day03", Debug, "symbols at {:?}", symbols);
+
+        Ok(Schematic {
+            symbols,
+            part_index,
+        })
+    }
+
+    fn part_one(schematic: &Schematic) -> SolutionResult {
+        Ok(schematic
+            .symbols
+            .iter()
+            .flat_map(|(coord, _)| find_neighboring_part_ids(&schematic.part_index, coord))
+            .sum())
+    }
+
+    fn part_two(schematic: &Schematic) -> SolutionResult {
+        Ok(schematic
+            .symbols
+            .iter()
+            .filter_map(|(coord, symbol)| {
+                let neighbors = find_neighboring_part_ids(&schematic.part_index, coord)
+                    .collect::<Vec<PartId>>();
+
+                // a gear
+                if neighbors.len() == 2 && *symbol == '*' {
+                    let (a, b) = neighbors.first().zip(neighbors.get(1)).unwrap();
+                    Some(a * b)
+                } else {
+                    None
+                }
+            })
+            .sum())
+    }
+}
+
+/// The engine schematic with its symbols located and its part numbers
+/// loaded into a spatial index.
+pub struct Schematic {
+    symbols: Vec<(Coord, char)>,
+    part_index: PartIndex,
+}
+
+fn find_neighboring_part_ids(
+    part_index: &PartIndex,
+    [x, y]: &Coord,
+) -> impl Iterator<Item = PartId> {
+    let envelope = rstar::AABB::from_corners([x - 1, y - 1], [x + 1, y + 1]);
+
+    part_index
+        .locate_in_envelope(&envelope)
+        .map(|pt| pt.data)
+        .collect::<HashSet<SpannedPartId>>()
+        .into_iter()
+        .map(|(id, span)| {
+            trace!("day03", Trace, "id: {:?} span: {:?}", id, span);
+            id
+        })
+}
+
+/// Every symbol with where it is. Lines are numbered as they are in the
+/// input, blank ones included, and columns count characters, not bytes,
+/// so symbols outside ASCII don't push the rest of their line over.
+/// Whitespace isn't a symbol, so indents and trailing spaces are ignored.
+fn find_symbols(input: &str) -> Vec<(Coord, char)> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|(_, c)| *c != '.' && !c.is_ascii_alphanumeric() && !c.is_whitespace())
+                .map(move |(x, c)| ([x as i32, y as i32], c))
+        })
+        .collect()
+}
+
+type Coord = [i32; 2];
+
+type PartIndex = rstar::RTree<PartPoint>;
+
+type PartPoint = rstar::primitives::GeomWithData<Coord, SpannedPartId>;
+
+fn build_part_index(input: &str) -> Result<PartIndex, ParseError> {
+    let line_re = Regex::new(r"[0-9]+").unwrap();
+    let mut points = vec![];
+    for (y, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        for m in line_re.find_iter(line) {
+            let start = line[..m.start()].chars().count();
+            let id = str::parse(m.as_str()).map_err(|e| {
+                ParseError::new("Malformed part number", m.as_str())
+                    .at_line(y + 1)
+                    .at_column(start + 1)
+                    .caused_by(e)
+            })?;
+            points.extend(
+                (start..start + m.len())
+                    .map(|x| PartPoint::new([x as i32, y as i32], (id, [start as i32, y as i32]))),
+            );
+        }
+    }
+    Ok(PartIndex::bulk_load(points))
+}
+
+type PartId = usize;
+
+type PartIdSpanStart = Coord;
+
+/// Tagging a part's id with some extra information about
+/// its location so that we can deduplicate these better.
+type SpannedPartId = (PartId, PartIdSpanStart);
+
+type SolutionResult = Result<usize, SolutionError>;
+
+#[test]
+fn full_input() {
+    let parsed = Day03::parse(include_str!("../../fixtures/day-03.txt")).unwrap();
+    assert_eq!(551094, Day03::part_one(&parsed).unwrap());
+    assert_eq!(80179647, Day03::part_two(&parsed).unwrap());
+}
+
+#[test]
+fn oversized_part_numbers_are_reported() {
+    let err = match Day03::parse("..99999999999999999999999*\n") {
+        Err(SolutionError::ParseError(e)) => e,
+        _ => panic!("expected a parse error"),
+    };
+    assert_eq!(
+        (Some(1), Some(3), "99999999999999999999999"),
+        (err.line(), err.column(), err.text())
+    );
+}
+
+#[test]
+fn errors_count_lines_and_columns_as_written() {
+    let err = match Day03::parse("\n\n  ..99999999999999999999999*\n") {
+        Err(SolutionError::ParseError(e)) => e,
+        _ => panic!("expected a parse error"),
+    };
+    assert_eq!((Some(3), Some(5)), (err.line(), err.column()));
+
+    // an indent moves every row alike, so the number and symbol still touch
+    let parsed = Day03::parse("\n  12.\n  ..*\n").unwrap();
+    assert_eq!(12, Day03::part_one(&parsed).unwrap());
+}
+
+#[test]
+fn symbols_outside_ascii() {
+    // the euro sign is a symbol like any other, one column wide, so both
+    // symbols touch both numbers
+    let parsed = Day03::parse("12€.\n..*3\n").unwrap();
+    assert_eq!(30, Day03::part_one(&parsed).unwrap());
+    assert_eq!(36, Day03::part_two(&parsed).unwrap());
+}
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = Vec<Card<'a>>;
+
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Card<'_>>, SolutionError> {
+        let parser = CardParser::new();
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(idx, s)| parser.parse(s).map_err(|e| e.at_line(idx + 1).into()))
+            .collect()
+    }
+
+    fn part_one(cards: &Vec<Card<'_>>) -> Result<usize, SolutionError> {
+        Ok(cards.iter().map(Card::score).sum())
+    }
+}
+
+type NumberSet<'a> = HashSet<&'a str>;
+
+pub struct Card<'a> {
+    // id: &'a str,
+    mine: NumberSet<'a>,
+    winning: NumberSet<'a>,
+}
+
+impl<'a> Card<'a> {
+    fn score(&self) -> usize {
+        let matches = self.mine.intersection(&self.winning).count();
+        if matches == 0 {
+            0
+        } else {
+            1 << (matches - 1)
+        }
+    }
+}
+
+struct CardParser {
+    parts_parser: Regex,
+    number_parser: Regex,
+}
+
+impl CardParser {
+    fn new() -> Self {
+        Self {
+            parts_parser: Regex::new(r"^Card +(?<id>\d+): (?<winning>[^|]+)\|(?<mine>.*)$")
+                .unwrap(),
+            number_parser: Regex::new(r"\d+").unwrap(),
+        }
+    }
+
+    fn parse<'a>(&self, s: &'a str) -> Result<Card<'a>, ParseError> {
+        let (_, [_id, winning_str, mine_str]) = self
+            .parts_parser
+            .captures(s)
+            .ok_or_else(|| ParseError::new("Input string not shaped like a card", s))?
+            .extract();
+        let winning = self
+            .number_parser
+            .find_iter(winning_str)
+            .map(|m| m.as_str());
+        let mine = self.number_parser.find_iter(mine_str).map(|m| m.as_str());
+
+        Ok(Card {
+            // id,
+            winning: winning.collect(),
+            mine: mine.collect(),
+        })
+    }
+}
+
+#[test]
+fn full_input() {
+    let parsed = Day04::parse(include_str!("../../fixtures/day-04.txt")).unwrap();
+    assert_eq!(26443, Day04::part_one(&parsed).unwrap());
+}
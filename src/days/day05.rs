@@ -0,0 +1,401 @@
+use std::{collections::HashMap, ops::Range};
+
+use regex::Regex;
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+use crate::trace;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Almanac<'a>;
+
+    type Answer = CategoryItemId;
+
+    fn parse(input: &str) -> Result<Almanac<'_>, SolutionError> {
+        let (seeds, db) = parse_input(input)?;
+        Ok(Almanac { seeds, db })
+    }
+
+    fn part_one(almanac: &Almanac<'_>) -> Result<CategoryItemId, SolutionError> {
+        let dests = find_destination_ids(&almanac.db, &"seed", &"location", &almanac.seeds[..]);
+        dests.min().ok_or(SolutionError::NoAnswer)
+    }
+}
+
+/// The seeds to be planted and the maps leading from each of them
+/// through to a location.
+pub struct Almanac<'a> {
+    seeds: Vec<CategoryItemId>,
+    db: LocMapDb<'a>,
+}
+
+fn find_destination_ids<'a>(
+    db: &'a LocMapDb<'a>,
+    source: &'a Category<'a>,
+    destination: &'a Category<'a>,
+    starting_ids: &'a [CategoryItemId],
+) -> impl Iterator<Item = CategoryItemId> + 'a {
+    starting_ids
+        .iter()
+        .filter_map(|id| traverse_to(db, source, destination, id))
+}
+
+fn traverse_to(
+    db: &LocMapDb<'_>,
+    source: &Category<'_>,
+    destination: &Category<'_>,
+    starting_id: &CategoryItemId,
+) -> Option<CategoryItemId> {
+    let mut current_source = *source;
+    let mut current_id = *starting_id;
+    while let Some(mapping) = db.lookup_mapping_by_source(&current_source) {
+        if let Some((dest, id)) = mapping.destination_for(&current_id) {
+            trace!(
+                "day05",
+                Trace,
+                "{} {} -> {} {}",
+                current_source,
+                current_id,
+                dest,
+                id
+            );
+            if dest == *destination {
+                return Some(id);
+            }
+
+            current_source = dest;
+            current_id = id;
+        } else {
+            trace!(
+                "day05",
+                Trace,
+                "{} {} has no mapping",
+                current_source,
+                current_id
+            );
+            return None;
+        }
+    }
+
+    trace!("day05", Trace, "no map leads on from {}", current_source);
+    None
+}
+
+type Category<'a> = &'a str;
+
+pub type CategoryItemId = i64;
+
+/// (source range, dest range)
+type MappingEntry = (Range<CategoryItemId>, Range<CategoryItemId>);
+
+#[derive(Debug)]
+struct LocMapping<'a> {
+    source: Category<'a>,
+    destination: Category<'a>,
+    mappings: Vec<MappingEntry>,
+}
+
+impl<'a> LocMapping<'a> {
+    fn new(
+        source: Category<'a>,
+        destination: Category<'a>,
+        mappings: impl IntoIterator<Item = MappingEntry>,
+    ) -> Self {
+        Self {
+            source,
+            destination,
+            mappings: mappings.into_iter().collect(),
+        }
+    }
+
+    /// Any source numbers that aren't mapped correspond to the same destination number.
+    /// So, seed number 10 corresponds to soil number 10.
+    fn destination_for(
+        &self,
+        source_id: &CategoryItemId,
+    ) -> Option<(Category<'a>, CategoryItemId)> {
+        self.mappings
+            .iter()
+            .find_map(|(source, dest)| {
+                if source.contains(source_id) {
+                    let offset = source_id - source.start;
+                    Some((self.destination, dest.start + offset))
+                } else {
+                    None
+                }
+            })
+            .or(Some((self.destination, *source_id)))
+    }
+}
+
+#[derive(Debug)]
+struct LocMapDb<'a> {
+    mappings: Vec<LocMapping<'a>>,
+    index_by_source: HashMap<Category<'a>, usize>,
+}
+
+impl<'a> LocMapDb<'a> {
+    fn new(mappings: impl IntoIterator<Item = LocMapping<'a>>) -> Self {
+        let (mappings, index_by_source) = mappings.into_iter().enumerate().fold(
+            (vec![], HashMap::new()),
+            |(mut ms, mut ibs), (idx, m)| {
+                ms.push(m);
+                let stored_m = ms.last().unwrap();
+                ibs.insert(stored_m.source, idx);
+                (ms, ibs)
+            },
+        );
+
+        Self {
+            mappings,
+            index_by_source,
+        }
+    }
+
+    fn lookup_mapping_by_source(&self, source: &Category<'a>) -> Option<&LocMapping<'a>> {
+        self.index_by_source
+            .get(source)
+            .and_then(|idx| self.mappings.get(*idx))
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<CategoryItemId>, LocMapDb<'_>), ParseError> {
+    let mut blocks = blocks(input).into_iter();
+    let seeds_block = blocks
+        .next()
+        .ok_or_else(|| ParseError::new("Missing seeds header", ""))?;
+    let (&(seeds_line, seeds_text), rest) = seeds_block
+        .split_first()
+        .expect("blocks hold at least one line");
+    let seeds = SeedsParser::new()
+        .parse(seeds_text)
+        .map_err(|e| e.at_line(seeds_line))?;
+    if let Some(&(line, text)) = rest.first() {
+        return Err(ParseError::new("Expected a blank line after the seeds", text).at_line(line));
+    }
+
+    let mapping_parser = MappingParser::new();
+    let mut mappings: Vec<LocMapping<'_>> = vec![];
+    let mut headers = vec![];
+    for block in blocks {
+        let mapping = mapping_parser.parse(&block)?;
+        let (line, header) = block[0];
+        if mappings.iter().any(|m| m.source == mapping.source) {
+            return Err(ParseError::new("Duplicate map source", header).at_line(line));
+        }
+        mappings.push(mapping);
+        headers.push((line, header));
+    }
+
+    // each category has at most one map out of it, so following the maps
+    // from a category either runs out or comes back round to it
+    let destinations: HashMap<Category<'_>, Category<'_>> =
+        mappings.iter().map(|m| (m.source, m.destination)).collect();
+    for (mapping, &(line, header)) in mappings.iter().zip(&headers) {
+        let mut current = mapping.destination;
+        for _ in 0..mappings.len() {
+            if current == mapping.source {
+                return Err(ParseError::new("Maps lead round in a cycle", header).at_line(line));
+            }
+            match destinations.get(current) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+    }
+
+    Ok((seeds, LocMapDb::new(mappings)))
+}
+
+/// The runs of non-blank lines in the input, each line with its 1-based
+/// line number.
+fn blocks(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push((idx + 1, line));
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+#[derive(Debug)]
+struct SeedsParser {
+    structure_re: Regex,
+    seed_re: Regex,
+}
+
+impl SeedsParser {
+    fn new() -> Self {
+        Self {
+            structure_re: Regex::new(r"^seeds: (?<seeds>.*)$").unwrap(),
+            seed_re: Regex::new(r"[0-9]+").unwrap(),
+        }
+    }
+
+    fn parse(&self, line: &str) -> Result<Vec<CategoryItemId>, ParseError> {
+        let seeds = self
+            .structure_re
+            .captures(line)
+            .and_then(|c| c.name("seeds"))
+            .ok_or_else(|| ParseError::new("Malformed seeds header", line))?;
+
+        self.seed_re
+            .find_iter(seeds.as_str())
+            .map(|m| {
+                str::parse(m.as_str()).map_err(|e| {
+                    ParseError::new("Malformed number", m.as_str())
+                        .at_column(seeds.start() + m.start() + 1)
+                        .caused_by(e)
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct MappingParser {
+    header_re: Regex,
+    mapping_re: Regex,
+}
+
+impl MappingParser {
+    fn new() -> Self {
+        Self {
+            header_re: Regex::new(r"^(?<source>\w+)-to-(?<destination>\w+) map:$").unwrap(),
+            mapping_re: Regex::new(
+                r"^(?<dest_range_start>[0-9]+) (?<source_range_start>[0-9]+) (?<range_size>[0-9]+)$",
+            )
+            .unwrap(),
+        }
+    }
+
+    /// A map's header and entries, each line with its line number.
+    fn parse<'s>(&self, block: &[(usize, &'s str)]) -> Result<LocMapping<'s>, ParseError> {
+        let (&(header_line, header), lines) =
+            block.split_first().expect("blocks hold at least one line");
+
+        let (_, [source, destination]) = self
+            .header_re
+            .captures(header)
+            .map(|c| c.extract())
+            .ok_or_else(|| {
+                ParseError::new("Malformed mapping header", header).at_line(header_line)
+            })?;
+
+        let mappings = lines
+            .iter()
+            .map(|&(line, l)| self.parse_entry(l).map_err(|e| e.at_line(line)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(LocMapping::new(source, destination, mappings))
+    }
+
+    /// Each line within a map contains three numbers: the destination range
+    /// start, the source range start, and the range length.
+    fn parse_entry(&self, l: &str) -> Result<MappingEntry, ParseError> {
+        let captures = self
+            .mapping_re
+            .captures(l)
+            .ok_or_else(|| ParseError::new("Mapping line malformed", l))?;
+        let number = |name, message| {
+            let m = captures.name(name).expect("every group takes part");
+            str::parse::<CategoryItemId>(m.as_str())
+                .map(|n| (n, m.start() + 1))
+                .map_err(|e| {
+                    ParseError::new(message, m.as_str())
+                        .at_column(m.start() + 1)
+                        .caused_by(e)
+                })
+        };
+        let (dest_start, _) = number("dest_range_start", "Malformed destination range start")?;
+        let (source_start, _) = number("source_range_start", "Malformed source range start")?;
+        let (range_size, size_column) = number("range_size", "Malformed range size")?;
+
+        let ends = source_start
+            .checked_add(range_size)
+            .zip(dest_start.checked_add(range_size));
+        let (source_end, dest_end) = ends.ok_or_else(|| {
+            ParseError::new("Range runs past the largest id", l).at_column(size_column)
+        })?;
+
+        Ok((source_start..source_end, dest_start..dest_end))
+    }
+}
+
+#[test]
+fn full_input() {
+    let parsed = Day05::parse(include_str!("../../fixtures/day-05.txt")).unwrap();
+    assert_eq!(579439039, Day05::part_one(&parsed).unwrap());
+}
+
+#[test]
+fn mapping_errors_point_at_their_line() {
+    let err = |input| match Day05::parse(input) {
+        Err(SolutionError::ParseError(e)) => (e.line(), e.column(), e.text().to_owned()),
+        _ => panic!("expected a parse error"),
+    };
+
+    assert_eq!(
+        (
+            Some(4),
+            Some(23),
+            "9223372036854775807 1 9223372036854775807".to_owned()
+        ),
+        err("seeds: 1\n\na-to-b map:\n9223372036854775807 1 9223372036854775807\n")
+    );
+    assert_eq!(
+        (Some(5), None, "1 2".to_owned()),
+        err("seeds: 1\n\na-to-b map:\n1 2 3\n1 2\n")
+    );
+    assert_eq!(
+        (Some(2), None, "a-to-b map:".to_owned()),
+        err("seeds: 1\na-to-b map:\n1 2 3\n")
+    );
+}
+
+#[test]
+fn map_chains_must_lead_somewhere() {
+    let err = |input| match Day05::parse(input) {
+        Err(SolutionError::ParseError(e)) => (e.message(), e.line(), e.text().to_owned()),
+        _ => panic!("expected a parse error"),
+    };
+
+    assert_eq!(
+        (
+            "Maps lead round in a cycle",
+            Some(3),
+            "seed-to-soil map:".to_owned()
+        ),
+        err("seeds: 1\n\nseed-to-soil map:\n1 1 1\n\nsoil-to-seed map:\n1 1 1\n")
+    );
+    assert_eq!(
+        (
+            "Maps lead round in a cycle",
+            Some(3),
+            "seed-to-seed map:".to_owned()
+        ),
+        err("seeds: 1\n\nseed-to-seed map:\n1 1 1\n")
+    );
+    assert_eq!(
+        (
+            "Duplicate map source",
+            Some(6),
+            "seed-to-dirt map:".to_owned()
+        ),
+        err("seeds: 1\n\nseed-to-soil map:\n1 1 1\n\nseed-to-dirt map:\n1 1 1\n")
+    );
+}
@@ -0,0 +1,229 @@
+use regex::Regex;
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+use crate::trace;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = Vec<Race>;
+
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Race>, SolutionError> {
+        Ok(RaceParser::new().parse(input)?)
+    }
+
+    fn part_one(input: &Vec<Race>) -> Result<usize, SolutionError> {
+        Ok(input
+            .iter()
+            .map(|race| {
+                let successes = count_successes(race);
+                trace!("day06", Info, "{:?}: {} ways to win", race, successes);
+                successes
+            })
+            .product())
+    }
+}
+
+fn count_successes(
+    Race {
+        duration,
+        distance_record,
+    }: &Race,
+) -> usize {
+    // The shape of possible max distances traces out a parabola: y = wait * (duration - wait)
+    // we can use the quadratic function to find the places where this parabola intersects
+    // the line of the distance record and those two roots are the minimum wait to win
+    // and the maximum wait.
+    let target = *distance_record as f64 + 1.0;
+    let sqrt_component = f64::sqrt(f64::powi(*duration as f64, 2) - (4.0 * target));
+    let min = f64::ceil((*duration as f64 - sqrt_component) / 2.0);
+    let max = f64::floor((*duration as f64 + sqrt_component) / 2.0);
+    (max - min) as usize + 1 // + 1 because range is inclusive
+}
+
+// My first pass iterated over possible wait times, plugging the wait time in for x
+// in the quadratic equation above. It gave me the right answer and allowed me to
+// confirm that my "find the root" approach was working correctly.
+// fn successes<'r>(race: &'r Race) -> impl Iterator<Item = usize> + 'r {
+//     trials(race).filter(|dist| dist > &race.distance_record)
+// }
+
+// fn trials<'r>(
+//     Race {
+//         duration,
+//         distance_record: _,
+//     }: &'r Race,
+// ) -> impl Iterator<Item = usize> + 'r {
+//     (1..*duration).map(|wait_duration| wait_duration * (*duration - wait_duration))
+// }
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    duration: usize,
+    distance_record: usize,
+}
+
+struct RaceParser {
+    line_re: Regex,
+    number_re: Regex,
+}
+
+impl RaceParser {
+    fn new() -> Self {
+        Self {
+            line_re: Regex::new(r"^(?<label>Time|Distance):(?<values>[\d ]*)$").unwrap(),
+            number_re: Regex::new(r"\d+").unwrap(),
+        }
+    }
+
+    /// Races are laid out in columns: a row of durations followed
+    /// by a row of distance records.
+    fn parse(&self, input: &str) -> Result<Vec<Race>, ParseError> {
+        let mut durations = None;
+        let mut distance_records = None;
+
+        for (idx, raw) in input.lines().enumerate() {
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            // columns count from the start of the untrimmed line
+            let indent = raw.len() - raw.trim_start().len();
+
+            let captures = self
+                .line_re
+                .captures(line)
+                .ok_or_else(|| ParseError::new("Malformed race row", line).at_line(idx + 1))?;
+            let values = captures.name("values").expect("every group takes part");
+            let numbers = self
+                .number_re
+                .find_iter(values.as_str())
+                .map(|m| {
+                    let column = indent + values.start() + m.start() + 1;
+                    str::parse::<usize>(m.as_str())
+                        .map(|n| (n, column))
+                        .map_err(|e| {
+                            ParseError::new("Malformed number", m.as_str())
+                                .at_line(idx + 1)
+                                .at_column(column)
+                                .caused_by(e)
+                        })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let row = Row {
+                line: idx + 1,
+                text: line,
+                numbers,
+            };
+            let (slot, message) = match &captures["label"] {
+                "Time" => (&mut durations, "Duplicate time row"),
+                _ => (&mut distance_records, "Duplicate distance row"),
+            };
+            if slot.is_some() {
+                return Err(ParseError::new(message, line).at_line(idx + 1));
+            }
+            *slot = Some(row);
+        }
+
+        let (durations, distance_records) = match (durations, distance_records) {
+            (Some(d), Some(r)) => (d, r),
+            (Some(row), None) => {
+                return Err(ParseError::new("Missing distance row", row.text).at_line(row.line))
+            }
+            (None, Some(row)) => {
+                return Err(ParseError::new("Missing time row", row.text).at_line(row.line))
+            }
+            (None, None) => return Err(ParseError::new("Missing time and distance rows", "")),
+        };
+
+        // point at the first number without a partner in the other row
+        let (longer, shorter) = if durations.numbers.len() > distance_records.numbers.len() {
+            (&durations, &distance_records)
+        } else {
+            (&distance_records, &durations)
+        };
+        if let Some(&(_, column)) = longer.numbers.get(shorter.numbers.len()) {
+            return Err(ParseError::new("Mismatched race columns", longer.text)
+                .at_line(longer.line)
+                .at_column(column));
+        }
+
+        Ok(durations
+            .numbers
+            .into_iter()
+            .zip(distance_records.numbers)
+            .map(|((duration, _), (distance_record, _))| Race {
+                duration,
+                distance_record,
+            })
+            .collect())
+    }
+}
+
+/// One row of the race table, with each number's column.
+struct Row<'s> {
+    line: usize,
+    text: &'s str,
+    numbers: Vec<(usize, usize)>,
+}
+
+#[test]
+fn parses_race_columns() {
+    let input = r#"
+Time:      7  15   30
+Distance:  9  40  200
+"#;
+
+    let races = Day06::parse(input).unwrap();
+    assert_eq!(
+        vec![
+            Race {
+                duration: 7,
+                distance_record: 9,
+            },
+            Race {
+                duration: 15,
+                distance_record: 40,
+            },
+            Race {
+                duration: 30,
+                distance_record: 200,
+            },
+        ],
+        races
+    );
+}
+
+#[test]
+fn full_input() {
+    let parsed = Day06::parse(include_str!("../../fixtures/day-06.txt")).unwrap();
+    assert_eq!(138915, Day06::part_one(&parsed).unwrap());
+}
+
+#[test]
+fn race_table_errors_point_at_their_row() {
+    let err = RaceParser::new()
+        .parse("Time:      7  15   30\nDistance:  9  40\n")
+        .unwrap_err();
+    assert_eq!(
+        (Some(1), Some(20), "Time:      7  15   30"),
+        (err.line(), err.column(), err.text())
+    );
+
+    let err = RaceParser::new().parse("\n  Time: 7\n").unwrap_err();
+    assert_eq!((Some(2), "Time: 7"), (err.line(), err.text()));
+
+    let err = RaceParser::new()
+        .parse("Time: 7\nDistance: 9\nTime: 8\n")
+        .unwrap_err();
+    assert_eq!(
+        ("Duplicate time row", Some(3), "Time: 8"),
+        (err.message(), err.line(), err.text())
+    );
+}
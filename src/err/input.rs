@@ -0,0 +1,41 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+
+/// Failure to get hold of a puzzle input at all.
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf, io::Error),
+    Unreadable(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl InputError {
+    pub fn from_io(path: PathBuf, e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::NotFound => Self::Missing(path, e),
+            _ => Self::Unreadable(path, e),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Missing(_, e) | Self::Unreadable(_, e) | Self::Stdin(e) => Some(e),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(path, _) => write!(f, "no puzzle input at {}", path.display()),
+            Self::Unreadable(path, _) => {
+                write!(f, "could not read puzzle input {}", path.display())
+            }
+            Self::Stdin(_) => write!(f, "could not read puzzle input from stdin"),
+        }
+    }
+}
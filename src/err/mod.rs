@@ -1,4 +1,11 @@
-mod solution;
 mod boxed;
+mod input;
+mod parse;
+mod report;
+mod solution;
 
-pub use solution::*;
\ No newline at end of file
+pub use boxed::BoxedError;
+pub use input::*;
+pub use parse::*;
+pub use report::*;
+pub use solution::*;
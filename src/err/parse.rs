@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+use super::BoxedError;
+
+/// Failure to turn a piece of puzzle input into a day's data model.
+///
+/// Carries the offending text along with as much location information
+/// as the parser had on hand, so the bad input can actually be found.
+/// Line and column numbers are 1-based.
+#[derive(Debug)]
+pub struct ParseError {
+    message: &'static str,
+    text: String,
+    day: Option<u8>,
+    line: Option<usize>,
+    column: Option<usize>,
+    source: Option<BoxedError>,
+}
+
+impl ParseError {
+    pub fn new(message: &'static str, text: impl Into<String>) -> Self {
+        Self {
+            message,
+            text: text.into(),
+            day: None,
+            line: None,
+            column: None,
+            source: None,
+        }
+    }
+
+    pub fn on_day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+
+    /// Attach the day-specific error that triggered this one.
+    pub fn caused_by(mut self, source: impl Into<BoxedError>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+}
+
+impl Error for ParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|e| e as &(dyn Error + 'static))
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = [
+            self.day.map(|d| format!("day {}", d)),
+            self.line.map(|l| format!("line {}", l)),
+            self.column.map(|c| format!("column {}", c)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(", "))?;
+        }
+
+        write!(f, "{} on {:?}", self.message, self.text)
+    }
+}
+
+#[test]
+fn display_includes_location() {
+    let err = ParseError::new("Malformed number", "12x")
+        .on_day(5)
+        .at_line(3)
+        .at_column(7);
+
+    assert_eq!(
+        r#"day 5, line 3, column 7: Malformed number on "12x""#,
+        err.to_string()
+    );
+}
+
+#[test]
+fn source_chains_to_cause() {
+    let cause = std::io::Error::other("underlying");
+    let err = ParseError::new("Bad line", "").caused_by(cause);
+
+    assert_eq!("underlying", err.source().unwrap().to_string());
+}
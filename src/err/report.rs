@@ -0,0 +1,31 @@
+use std::error::Error;
+use std::process::ExitCode;
+
+/// Print an error and its chain of causes to stderr, turning the
+/// outcome of a run into a process exit code.
+pub fn report<E: Error>(result: Result<(), E>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            let mut source = e.source();
+            while let Some(cause) = source {
+                eprintln!("  caused by: {}", cause);
+                source = cause.source();
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Render an error and its causes on a single line.
+pub fn describe(e: &dyn Error) -> String {
+    let mut description = e.to_string();
+    let mut source = e.source();
+    while let Some(cause) = source {
+        description.push_str(": ");
+        description.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    description
+}
@@ -1,22 +1,76 @@
 use std::error::Error;
+use std::io;
+
+use super::{InputError, ParseError};
 
 #[derive(Debug)]
 pub enum SolutionError {
-    ParseError(&'static str, String),
-    NoAnswer
+    ParseError(ParseError),
+    /// A query about the puzzle input, rather than the input itself, was
+    /// malformed.
+    Query(ParseError),
+    Input(InputError),
+    Io(io::Error),
+    NoAnswer,
+    /// The day has no solution for the requested part yet.
+    Unsolved,
+    /// No solution is registered for the day at all.
+    UnknownDay(u8),
+}
+
+impl SolutionError {
+    /// Tag any parse error with the day it came from.
+    pub fn in_day(self, day: u8) -> Self {
+        match self {
+            Self::ParseError(e) => Self::ParseError(e.on_day(day)),
+            other => other,
+        }
+    }
 }
 
-impl Error for SolutionError {}
+impl Error for SolutionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::ParseError(e) | Self::Query(e) => Some(e),
+            Self::Input(e) => Some(e),
+            Self::Io(e) => Some(e),
+            Self::NoAnswer | Self::Unsolved | Self::UnknownDay(_) => None,
+        }
+    }
+}
 
 impl std::fmt::Display for SolutionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Solution finding failed {}",
+            "Solution finding failed: {}",
             match self {
-                Self::ParseError(m, input) => format!("Parse error {} on {}", m, input),
-                Self::NoAnswer => "No answer found!".to_owned()
+                Self::ParseError(_) => "invalid puzzle input".to_owned(),
+                Self::Query(_) => "invalid query".to_owned(),
+                Self::Input(_) => "puzzle input unavailable".to_owned(),
+                Self::Io(_) => "I/O failure".to_owned(),
+                Self::NoAnswer => "no answer found".to_owned(),
+                Self::Unsolved => "part not solved yet".to_owned(),
+                Self::UnknownDay(day) => format!("day {} has no registered solution", day),
             }
         )
     }
 }
+
+impl From<ParseError> for SolutionError {
+    fn from(e: ParseError) -> Self {
+        Self::ParseError(e)
+    }
+}
+
+impl From<InputError> for SolutionError {
+    fn from(e: InputError) -> Self {
+        Self::Input(e)
+    }
+}
+
+impl From<io::Error> for SolutionError {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
@@ -0,0 +1,202 @@
+//! Puzzle examples as data. Each example lives at
+//! `examples/day-NN/<name>.txt` and starts with a header of expected
+//! answers, separated from the puzzle input by a `---` line:
+//!
+//! ```text
+//! part-one: 4361
+//! part-two: 467835
+//! ---
+//! 467..114..
+//! ...*......
+//! ```
+//!
+//! Either part may be left out of the header to skip checking it, and
+//! header lines starting with `#` are comments.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, Check, RecordedAnswer};
+use crate::err::{describe, InputError, ParseError, SolutionError};
+use crate::input::Source;
+
+/// Where examples live, relative to the crate root.
+pub const DIR: &str = "examples";
+
+#[derive(Debug)]
+pub struct Example {
+    pub day: u8,
+    pub path: PathBuf,
+    pub expected: Vec<RecordedAnswer>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn load(day: u8, path: &Path) -> Result<Self, SolutionError> {
+        let text = Source::File(path.to_path_buf()).read()?;
+        Self::parse(day, path, &text)
+    }
+
+    pub fn parse(day: u8, path: &Path, text: &str) -> Result<Self, SolutionError> {
+        let mut expected = vec![];
+        let mut lines = text.split_inclusive('\n').enumerate();
+        let mut input_start = None;
+        let mut offset = 0;
+
+        for (idx, line) in lines.by_ref() {
+            offset += line.len();
+            let line = line.trim();
+            if line == "---" {
+                input_start = Some(offset);
+                break;
+            }
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                ParseError::new("Malformed example header", line).at_line(idx + 1)
+            })?;
+            let part = match key.trim() {
+                "part-one" => 1,
+                "part-two" => 2,
+                _ => {
+                    return Err(ParseError::new("Unknown example header key", key)
+                        .at_line(idx + 1)
+                        .into())
+                }
+            };
+            expected.push(RecordedAnswer {
+                day,
+                part,
+                input: path.to_path_buf(),
+                expected: value.trim().to_owned(),
+            });
+        }
+
+        let input_start = input_start
+            .ok_or_else(|| ParseError::new("Example is missing its `---` separator", ""))?;
+
+        Ok(Self {
+            day,
+            path: path.to_path_buf(),
+            expected,
+            input: text[input_start..].to_owned(),
+        })
+    }
+
+    pub fn check(&self) -> Vec<Check> {
+        answers::check(self.day, &self.input, self.expected.iter().collect())
+    }
+}
+
+/// Every example file under `root`, as `(day, path)` in day then name order.
+pub fn discover(root: &Path) -> Result<Vec<(u8, PathBuf)>, SolutionError> {
+    let read_dir = |dir: &Path| fs::read_dir(dir).map_err(|e| InputError::from_io(dir.into(), e));
+
+    let mut found = vec![];
+    for dir in read_dir(root)? {
+        let dir = dir?.path();
+        let Some(day) = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| n.strip_prefix("day-"))
+            .and_then(|n| n.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        for file in read_dir(&dir)? {
+            let path = file?.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                found.push((day, path));
+            }
+        }
+    }
+
+    found.sort();
+    Ok(found)
+}
+
+/// An example that was loaded and checked, or the reason it couldn't be.
+#[derive(Debug)]
+pub struct ExampleRun {
+    pub path: PathBuf,
+    pub checks: Result<Vec<Check>, SolutionError>,
+}
+
+impl ExampleRun {
+    pub fn passed(&self) -> bool {
+        self.checks
+            .as_ref()
+            .is_ok_and(|checks| checks.iter().all(Check::passed))
+    }
+
+    /// One line per problem with this example, empty if it passed.
+    pub fn failures(&self) -> Vec<String> {
+        match &self.checks {
+            Err(e) => vec![format!("{}: {}", self.path.display(), describe(e))],
+            Ok(checks) => checks
+                .iter()
+                .filter(|c| !c.passed())
+                .map(|c| {
+                    format!(
+                        "{}: part {}: expected {}, got {:?}",
+                        self.path.display(),
+                        c.answer.part,
+                        c.answer.expected,
+                        c.verdict
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Load and check every example under `root`.
+pub fn run_all(root: &Path) -> Result<Vec<ExampleRun>, SolutionError> {
+    Ok(discover(root)?
+        .into_iter()
+        .map(|(day, path)| ExampleRun {
+            checks: Example::load(day, &path).map(|example| example.check()),
+            path,
+        })
+        .collect())
+}
+
+#[test]
+fn header_is_split_from_input() {
+    let example = Example::parse(
+        6,
+        Path::new("examples/day-06/example.txt"),
+        "# from the puzzle\npart-one: 288\n---\nTime: 7\nDistance: 9\n",
+    )
+    .unwrap();
+
+    assert_eq!(1, example.expected.len());
+    assert_eq!(
+        (1, "288"),
+        (
+            example.expected[0].part,
+            example.expected[0].expected.as_str()
+        )
+    );
+    assert_eq!("Time: 7\nDistance: 9\n", example.input);
+}
+
+#[test]
+fn malformed_headers_are_rejected() {
+    let path = Path::new("example.txt");
+
+    assert!(Example::parse(6, path, "part-three: 1\n---\n").is_err());
+    assert!(Example::parse(6, path, "part-one: 288\nTime: 7\n").is_err());
+}
+
+#[test]
+fn every_example_passes() {
+    let runs = run_all(&Path::new(env!("CARGO_MANIFEST_DIR")).join(DIR)).unwrap();
+    let failures: Vec<String> = runs.iter().flat_map(ExampleRun::failures).collect();
+
+    assert!(!runs.is_empty(), "no examples found");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
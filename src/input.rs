@@ -0,0 +1,119 @@
+use std::env;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::err::{InputError, SolutionError};
+
+/// Where a day's puzzle input lives when no path is given.
+pub fn default_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day-{:02}.txt", day))
+}
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Resolve an optional command line path, where `-` means stdin and
+    /// nothing at all means the day's default input file.
+    pub fn resolve(day: u8, path: Option<&Path>) -> Self {
+        match path {
+            Some(p) if p == Path::new("-") => Self::Stdin,
+            Some(p) => Self::File(p.to_path_buf()),
+            None => Self::File(default_path(day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::File(path) => {
+                fs::read_to_string(path).map_err(|e| InputError::from_io(path.clone(), e))
+            }
+            Self::Stdin => {
+                let mut text = String::new();
+                io::stdin()
+                    .read_to_string(&mut text)
+                    .map_err(InputError::Stdin)?;
+                Ok(text)
+            }
+        }
+    }
+
+    /// Open the input for reading a line at a time, for inputs too large to
+    /// read in one go.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Self::File(path) => {
+                let file =
+                    fs::File::open(path).map_err(|e| InputError::from_io(path.clone(), e))?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            Self::Stdin => Ok(Box::new(io::stdin().lock())),
+        }
+    }
+}
+
+/// Read a day's puzzle input from `path` (`-` for stdin), or from its
+/// default location.
+pub fn load(day: u8, path: Option<&Path>) -> Result<String, SolutionError> {
+    Ok(Source::resolve(day, path).read()?)
+}
+
+/// Read a day's puzzle input from the path given as the first command
+/// line argument, for the single-day binaries.
+pub fn load_from_args(day: u8) -> Result<String, SolutionError> {
+    let path = env::args_os().nth(1).map(PathBuf::from);
+    load(day, path.as_deref())
+}
+
+/// Stable fingerprint of an input's text (64-bit FNV-1a, as hex), so
+/// results can be tied back to the input that produced them.
+pub fn checksum(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+#[test]
+fn checksums_are_stable() {
+    assert_eq!("cbf29ce484222325", checksum(""));
+    assert_eq!("af63dc4c8601ec8c", checksum("a"));
+}
+
+#[test]
+fn resolves_sources() {
+    assert_eq!(
+        Source::File(PathBuf::from("inputs/day-05.txt")),
+        Source::resolve(5, None)
+    );
+    assert_eq!(Source::Stdin, Source::resolve(5, Some(Path::new("-"))));
+    assert_eq!(
+        Source::File(PathBuf::from("mine.txt")),
+        Source::resolve(5, Some(Path::new("mine.txt")))
+    );
+}
+
+#[test]
+fn missing_files_are_reported_as_missing() {
+    let err = load(5, Some(Path::new("does/not/exist.txt"))).unwrap_err();
+
+    assert!(matches!(
+        err,
+        SolutionError::Input(InputError::Missing(ref path, _)) if path == Path::new("does/not/exist.txt")
+    ));
+}
+
+#[test]
+fn directories_are_reported_as_unreadable() {
+    let err = load(5, Some(Path::new("src"))).unwrap_err();
+
+    assert!(matches!(
+        err,
+        SolutionError::Input(InputError::Unreadable(_, _))
+    ));
+}
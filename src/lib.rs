@@ -1 +1,13 @@
-pub mod err;
\ No newline at end of file
+pub mod answers;
+pub mod bench;
+pub mod days;
+pub mod err;
+pub mod examples;
+pub mod input;
+pub mod output;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod table;
+pub mod trace;
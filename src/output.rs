@@ -0,0 +1,206 @@
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::err::{describe, ParseError, SolutionError};
+use crate::runner::DayRun;
+use crate::table::Table;
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Aligned table for people.
+    #[default]
+    Plain,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "plain" => Ok(Self::Plain),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(ParseError::new("Unknown output format", s)),
+        }
+    }
+}
+
+/// One day/part outcome, flattened for scripts and dashboards.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AnswerRecord {
+    pub day: u8,
+    /// Missing when the whole day failed before any part ran.
+    pub part: Option<u8>,
+    /// `ok`, `unsolved` or `error`.
+    pub status: &'static str,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: u128,
+    pub solve_ns: Option<u128>,
+    pub input_checksum: Option<String>,
+}
+
+pub fn records(runs: &[DayRun]) -> Vec<AnswerRecord> {
+    let mut records = vec![];
+    for run in runs {
+        let record = |part: Option<u8>| AnswerRecord {
+            day: run.day,
+            part,
+            status: "ok",
+            answer: None,
+            error: None,
+            parse_ns: run.parse_time.as_nanos(),
+            solve_ns: None,
+            input_checksum: run.checksum.clone(),
+        };
+
+        match &run.parts {
+            Ok(parts) => {
+                for part in parts {
+                    let mut record = AnswerRecord {
+                        solve_ns: Some(part.elapsed.as_nanos()),
+                        ..record(Some(part.part.number()))
+                    };
+                    match &part.answer {
+                        Ok(answer) => record.answer = Some(answer.clone()),
+                        Err(SolutionError::Unsolved) => record.status = "unsolved",
+                        Err(e) => {
+                            record.status = "error";
+                            record.error = Some(describe(e));
+                        }
+                    }
+                    records.push(record);
+                }
+            }
+            Err(e) => records.push(AnswerRecord {
+                status: "error",
+                error: Some(describe(e)),
+                ..record(None)
+            }),
+        }
+    }
+    records
+}
+
+pub fn render(runs: &[DayRun], format: Format) -> String {
+    let records = records(runs);
+    match format {
+        Format::Plain => {
+            let mut table = Table::new(["day", "part", "answer", "time"]);
+            for r in records.iter() {
+                let answer = match (r.status, &r.answer, &r.error) {
+                    (_, Some(answer), _) => answer.clone(),
+                    (_, _, Some(error)) => format!("error: {}", error),
+                    _ => "-".to_owned(),
+                };
+                // parsing is shared by both parts but counts towards each
+                let time = match r.status {
+                    "unsolved" => "-".to_owned(),
+                    _ => {
+                        let nanos = r.parse_ns + r.solve_ns.unwrap_or(0);
+                        format!(
+                            "{:.2?}",
+                            Duration::from_nanos(nanos.try_into().unwrap_or(u64::MAX))
+                        )
+                    }
+                };
+                table.row([
+                    r.day.to_string(),
+                    r.part.map_or("-".to_owned(), |p| p.to_string()),
+                    answer,
+                    time,
+                ]);
+            }
+            table.to_string()
+        }
+        Format::Json => {
+            let mut json =
+                serde_json::to_string_pretty(&records).expect("answer records always serialise");
+            json.push('\n');
+            json
+        }
+        Format::Csv => {
+            let mut csv = String::new();
+            csv_row(
+                &mut csv,
+                [
+                    "day",
+                    "part",
+                    "status",
+                    "answer",
+                    "error",
+                    "parse_ns",
+                    "solve_ns",
+                    "input_checksum",
+                ],
+            );
+            for r in records.iter() {
+                csv_row(
+                    &mut csv,
+                    [
+                        &r.day.to_string(),
+                        &r.part.map(|p| p.to_string()).unwrap_or_default(),
+                        r.status,
+                        r.answer.as_deref().unwrap_or(""),
+                        r.error.as_deref().unwrap_or(""),
+                        &r.parse_ns.to_string(),
+                        &r.solve_ns.map(|n| n.to_string()).unwrap_or_default(),
+                        r.input_checksum.as_deref().unwrap_or(""),
+                    ],
+                );
+            }
+            csv
+        }
+    }
+}
+
+/// Append a CSV row, quoting any field that needs it.
+pub fn csv_row<'a>(out: &mut String, fields: impl IntoIterator<Item = &'a str>) {
+    let fields: Vec<String> = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        })
+        .collect();
+    let _ = writeln!(out, "{}", fields.join(","));
+}
+
+#[test]
+fn csv_fields_are_quoted() {
+    let mut out = String::new();
+    csv_row(&mut out, ["1", "a,b", "say \"hi\""]);
+
+    assert_eq!("1,\"a,b\",\"say \"\"hi\"\"\"\n", out);
+}
+
+#[test]
+fn records_cover_every_part() {
+    let entry = crate::registry::lookup(6).unwrap();
+    let run = crate::runner::run(
+        entry,
+        "Time: 7 15 30\nDistance: 9 40 200\n",
+        &crate::solution::Part::ALL,
+    );
+    let failed = DayRun::unloaded(5, SolutionError::NoAnswer);
+
+    let records = records(&[run, failed]);
+
+    assert_eq!(3, records.len());
+    assert_eq!(Some("288".to_owned()), records[0].answer);
+    assert_eq!("unsolved", records[1].status);
+    assert_eq!(
+        (5, None, "error"),
+        (records[2].day, records[2].part, records[2].status)
+    );
+    assert!(render(&[], Format::Csv).starts_with("day,part,status,"));
+}
@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+
+use crate::days::*;
+use crate::err::SolutionError;
+use crate::solution::{Part, Solution};
+
+/// Every solved day, in calendar order.
+pub static REGISTRY: &[Entry] = &[
+    Entry::new::<day01::Day01>(),
+    Entry::new::<day02::Day02>(),
+    Entry::new::<day03::Day03>(),
+    Entry::new::<day04::Day04>(),
+    Entry::new::<day05::Day05>(),
+    Entry::new::<day06::Day06>(),
+];
+
+pub fn lookup(day: u8) -> Option<&'static Entry> {
+    REGISTRY.iter().find(|entry| entry.day == day)
+}
+
+/// A registered day with its input and answer types erased, so days
+/// with different data models can live side by side.
+pub struct Entry {
+    pub day: u8,
+    parse: for<'a> fn(&'a str) -> Result<Box<dyn Parsed + 'a>, SolutionError>,
+}
+
+impl Entry {
+    pub const fn new<S: Solution + 'static>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: parse_erased::<S>,
+        }
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> Result<Box<dyn Parsed + 'a>, SolutionError> {
+        (self.parse)(input).map_err(|e| e.in_day(self.day))
+    }
+}
+
+/// A day's parsed input, ready to be solved.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<String, SolutionError>;
+}
+
+struct ParsedInput<'a, S: Solution>(S::Input<'a>, PhantomData<S>);
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Result<String, SolutionError> {
+        match part {
+            Part::One => S::part_one(&self.0),
+            Part::Two => S::part_two(&self.0),
+        }
+        .map(|answer| answer.to_string())
+        .map_err(|e| e.in_day(S::DAY))
+    }
+}
+
+fn parse_erased<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed + '_>, SolutionError> {
+    let parsed = S::parse(input)?;
+    Ok(Box::new(ParsedInput::<S>(parsed, PhantomData)))
+}
+
+#[test]
+fn registry_is_in_calendar_order() {
+    let days: Vec<u8> = REGISTRY.iter().map(|entry| entry.day).collect();
+
+    assert!(days.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", days);
+    assert!(days.starts_with(&[1, 2, 3, 4, 5, 6]));
+    assert!(lookup(5).is_some());
+    assert!(lookup(25).is_none());
+}
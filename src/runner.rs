@@ -0,0 +1,99 @@
+use std::time::{Duration, Instant};
+
+use crate::err::SolutionError;
+use crate::input;
+use crate::registry::Entry;
+use crate::solution::Part;
+
+/// Everything that happened while running one day against one input.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    /// Checksum of the input text, if it could be loaded.
+    pub checksum: Option<String>,
+    pub parse_time: Duration,
+    /// Fails as a whole when the input could not be loaded or parsed.
+    pub parts: Result<Vec<PartRun>, SolutionError>,
+}
+
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Result<String, SolutionError>,
+    pub elapsed: Duration,
+}
+
+impl DayRun {
+    /// A day whose input couldn't even be loaded.
+    pub fn unloaded(day: u8, e: SolutionError) -> Self {
+        Self {
+            day,
+            checksum: None,
+            parse_time: Duration::ZERO,
+            parts: Err(e),
+        }
+    }
+
+    /// Whether anything went wrong. Parts without a solution yet don't count.
+    pub fn failed(&self) -> bool {
+        match &self.parts {
+            Err(_) => true,
+            Ok(parts) => parts
+                .iter()
+                .any(|p| matches!(&p.answer, Err(e) if !matches!(e, SolutionError::Unsolved))),
+        }
+    }
+}
+
+/// Parse `input` for the given day and solve each of `parts` from it.
+pub fn run(entry: &Entry, input: &str, parts: &[Part]) -> DayRun {
+    let start = Instant::now();
+    let parsed = entry.parse(input);
+    let parse_time = start.elapsed();
+
+    DayRun {
+        day: entry.day,
+        checksum: Some(input::checksum(input)),
+        parse_time,
+        parts: parsed.map(|parsed| {
+            parts
+                .iter()
+                .map(|&part| {
+                    let start = Instant::now();
+                    let answer = parsed.solve(part);
+                    PartRun {
+                        part,
+                        answer,
+                        elapsed: start.elapsed(),
+                    }
+                })
+                .collect()
+        }),
+    }
+}
+
+#[test]
+fn runs_a_registered_day() {
+    let entry = crate::registry::lookup(4).unwrap();
+    let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+"#;
+
+    let run = run(entry, input, &Part::ALL);
+
+    assert!(!run.failed());
+    assert_eq!(Some(input::checksum(input)), run.checksum);
+    let parts = run.parts.unwrap();
+    assert_eq!("10", parts[0].answer.as_ref().unwrap());
+    assert!(matches!(parts[1].answer, Err(SolutionError::Unsolved)));
+}
+
+#[test]
+fn parse_failures_fail_the_run() {
+    let entry = crate::registry::lookup(4).unwrap();
+
+    let run = run(entry, "not a card", &Part::ALL);
+
+    assert!(run.failed());
+}
@@ -0,0 +1,230 @@
+//! Generates the boilerplate for a new day: a module implementing
+//! [`Solution`](crate::solution::Solution) with a regex-based parser,
+//! its registry entry, an empty input file and an example fixture.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::err::{InputError, ParseError, SolutionError};
+use crate::examples;
+use crate::input;
+
+/// Create every file a new day needs under the crate at `root`, returning
+/// the paths written or updated.
+pub fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>, SolutionError> {
+    let module = root.join(format!("src/days/day{:02}.rs", day));
+    let mod_rs = root.join("src/days/mod.rs");
+    let registry = root.join("src/registry.rs");
+    let input = root.join(input::default_path(day));
+    let example = root.join(example_path(day));
+
+    for path in [&module, &example] {
+        if path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            )
+            .into());
+        }
+    }
+
+    // work out every edit before touching anything, so a failure
+    // doesn't leave a half-registered day behind
+    let mod_rs_text = declare(&read(&mod_rs)?, day)?;
+    let registry_text = register(&read(&registry)?, day)?;
+
+    fs::write(&module, module_source(day))?;
+    fs::write(&mod_rs, mod_rs_text)?;
+    fs::write(&registry, registry_text)?;
+    for path in [&input, &example] {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+    }
+    if !input.exists() {
+        fs::write(&input, "")?;
+    }
+    fs::write(&example, EXAMPLE)?;
+
+    Ok(vec![module, mod_rs, registry, input, example])
+}
+
+fn read(path: &Path) -> Result<String, InputError> {
+    fs::read_to_string(path).map_err(|e| InputError::from_io(path.into(), e))
+}
+
+fn example_path(day: u8) -> PathBuf {
+    PathBuf::from(examples::DIR)
+        .join(format!("day-{:02}", day))
+        .join("example-one.txt")
+}
+
+/// Add `pub mod dayNN;` to the days module, keeping declarations sorted.
+pub fn declare(mod_rs: &str, day: u8) -> Result<String, SolutionError> {
+    insert_sorted(
+        mod_rs,
+        &Regex::new(r"^pub mod day(\d+);$").unwrap(),
+        day,
+        format!("pub mod day{:02};", day),
+    )
+    .ok_or_else(|| ParseError::new("No day modules declared", "src/days/mod.rs").into())
+}
+
+/// Add the day to the registry, keeping it in calendar order.
+pub fn register(registry: &str, day: u8) -> Result<String, SolutionError> {
+    insert_sorted(
+        registry,
+        &Regex::new(r"^(\s*)Entry::new::<day(\d+)::Day\d+>\(\),$").unwrap(),
+        day,
+        format!("Entry::new::<day{:02}::Day{:02}>(),", day, day),
+    )
+    .ok_or_else(|| ParseError::new("No registry entries found", "src/registry.rs").into())
+}
+
+/// Insert `line` among the lines matching `re` (whose last capture is a
+/// day number), before the first one for a later day. Copies the
+/// indentation of its neighbours when `re` captures it first.
+fn insert_sorted(text: &str, re: &Regex, day: u8, line: String) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let matches: Vec<(usize, u8, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, l)| {
+            let caps = re.captures(l)?;
+            let number = caps.get(caps.len() - 1)?.as_str().parse().ok()?;
+            let indent = if caps.len() > 2 {
+                caps.get(1).map_or("", |m| m.as_str())
+            } else {
+                ""
+            };
+            Some((idx, number, indent))
+        })
+        .collect();
+
+    let (_, _, indent) = matches.first()?;
+    let at = matches
+        .iter()
+        .find(|(_, number, _)| *number > day)
+        .map_or_else(|| matches.last().unwrap().0 + 1, |(idx, _, _)| *idx);
+
+    let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    out.insert(at, format!("{}{}", indent, line));
+    Some(out.join("\n") + "\n")
+}
+
+const EXAMPLE: &str = "\
+# Paste the example from the puzzle below the separator and record
+# its answers here, e.g. `part-one: 42`.
+---
+";
+
+/// Source for a fresh day module, shaped like the existing days: a
+/// regex-based parser struct whose errors point at the offending line.
+pub fn module_source(day: u8) -> String {
+    TEMPLATE
+        .replace("{day}", &day.to_string())
+        .replace("{day:02}", &format!("{:02}", day))
+}
+
+const TEMPLATE: &str = r#"use regex::Regex;
+
+use crate::err::{ParseError, SolutionError};
+use crate::solution::Solution;
+
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {
+    const DAY: u8 = {day};
+
+    type Input<'a> = Vec<Entry<'a>>;
+
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<Entry<'_>>, SolutionError> {
+        let parser = EntryParser::new();
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(idx, s)| parser.parse(s).map_err(|e| e.at_line(idx + 1).into()))
+            .collect()
+    }
+
+    fn part_one(_entries: &Vec<Entry<'_>>) -> Result<usize, SolutionError> {
+        Err(SolutionError::Unsolved)
+    }
+}
+
+/// One line of puzzle input.
+pub struct Entry<'a> {
+    pub text: &'a str,
+}
+
+struct EntryParser {
+    line_re: Regex,
+}
+
+impl EntryParser {
+    fn new() -> Self {
+        Self {
+            line_re: Regex::new(r"^(?<text>.+)$").unwrap(),
+        }
+    }
+
+    fn parse<'a>(&self, s: &'a str) -> Result<Entry<'a>, ParseError> {
+        let (_, [text]) = self
+            .line_re
+            .captures(s)
+            .ok_or_else(|| ParseError::new("Malformed line", s))?
+            .extract();
+
+        Ok(Entry { text })
+    }
+}
+"#;
+
+#[test]
+fn registers_days_in_order() {
+    let registry = "pub static REGISTRY: &[Entry] = &[\n    Entry::new::<day01::Day01>(),\n    Entry::new::<day09::Day09>(),\n];\n";
+
+    assert_eq!(
+        "pub static REGISTRY: &[Entry] = &[\n    Entry::new::<day01::Day01>(),\n    Entry::new::<day07::Day07>(),\n    Entry::new::<day09::Day09>(),\n];\n",
+        register(registry, 7).unwrap()
+    );
+    assert_eq!(
+        "pub mod day01;\npub mod day02;\n",
+        declare("pub mod day01;\n", 2).unwrap()
+    );
+    assert!(register("", 7).is_err());
+}
+
+#[test]
+fn generates_a_new_day() {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+    fs::create_dir_all(root.join("src/days")).unwrap();
+    fs::write(root.join("src/days/mod.rs"), "pub mod day01;\n").unwrap();
+    fs::write(
+        root.join("src/registry.rs"),
+        "&[\n    Entry::new::<day01::Day01>(),\n];\n",
+    )
+    .unwrap();
+
+    let written = generate(&root, 2);
+    let second_attempt = generate(&root, 2);
+    let module = fs::read_to_string(root.join("src/days/day02.rs"));
+    let example = root.join("examples/day-02/example-one.txt");
+    let example_exists = example.exists();
+    let example_parses = examples::Example::load(2, &example);
+    fs::remove_dir_all(&root).unwrap();
+
+    assert_eq!(5, written.unwrap().len());
+    assert!(second_attempt.is_err());
+    assert!(module
+        .unwrap()
+        .contains("impl Solution for Day02 {\n    const DAY: u8 = 2;"));
+    assert!(example_exists);
+    assert!(example_parses.unwrap().expected.is_empty());
+}
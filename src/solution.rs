@@ -0,0 +1,75 @@
+use std::fmt::{self, Display};
+
+use crate::err::SolutionError;
+
+/// One day's puzzle, split into the phases every day shares: turning the
+/// raw input into a data model, then answering each part from that model.
+///
+/// Keeping `parse` separate lets callers time (or reuse) the parsed input
+/// independently of solving.
+pub trait Solution {
+    /// Day of the advent calendar this solves.
+    const DAY: u8;
+
+    /// Parsed puzzle input. Allowed to borrow from the raw text.
+    type Input<'a>;
+
+    type Answer: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, SolutionError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Result<Self::Answer, SolutionError>;
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Self::Answer, SolutionError> {
+        Err(SolutionError::Unsolved)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// Parse `input` and print the answer to every part the day has solved.
+pub fn print_answers<S: Solution>(input: &str) -> Result<(), SolutionError> {
+    let parsed = S::parse(input).map_err(|e| e.in_day(S::DAY))?;
+    for part in Part::ALL {
+        let answer = match part {
+            Part::One => S::part_one(&parsed),
+            Part::Two => S::part_two(&parsed),
+        };
+        match answer {
+            Ok(answer) => println!("part {}: {}", part, answer),
+            Err(SolutionError::Unsolved) => (),
+            Err(e) => return Err(e.in_day(S::DAY)),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,151 @@
+//! Opt-in diagnostics for explaining how a day arrives at its answer.
+//!
+//! Everything is silent until [`configure`] turns a target on. Targets
+//! are named after the day emitting them (`day01`, `day05`, ...) and
+//! messages go to stderr so they never mix with answers.
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+use crate::err::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Milestones, e.g. a summary per input line.
+    Info,
+    /// Intermediate values worth checking when an answer is off.
+    Debug,
+    /// Every step, e.g. each hop of a lookup chain.
+    Trace,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "INFO",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        })
+    }
+}
+
+impl FromStr for Level {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Self::Info),
+            "debug" => Ok(Self::Debug),
+            "trace" => Ok(Self::Trace),
+            _ => Err(ParseError::new("Unknown trace level", s)),
+        }
+    }
+}
+
+/// Which targets are traced, and how verbosely.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    /// Level for targets without their own entry. `None` silences them.
+    pub default: Option<Level>,
+    pub targets: Vec<(String, Level)>,
+}
+
+impl Config {
+    /// Enable every target up to `level`.
+    pub fn everything(level: Level) -> Self {
+        Self {
+            default: Some(level),
+            targets: vec![],
+        }
+    }
+
+    /// Add a target from a `target` or `target=level` spec. A bare
+    /// target is traced at the most verbose level.
+    pub fn with_spec(mut self, spec: &str) -> Result<Self, ParseError> {
+        let (target, level) = match spec.split_once('=') {
+            Some((target, level)) => (target, level.parse()?),
+            None => (spec, Level::Trace),
+        };
+        if target.is_empty() {
+            return Err(ParseError::new("Missing trace target", spec));
+        }
+        self.targets.push((target.to_owned(), level));
+        Ok(self)
+    }
+
+    pub fn level_for(&self, target: &str) -> Option<Level> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(t, _)| t == target)
+            .map(|(_, level)| *level)
+            .or(self.default)
+    }
+
+    fn is_silent(&self) -> bool {
+        self.default.is_none() && self.targets.is_empty()
+    }
+}
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static CONFIG: RwLock<Config> = RwLock::new(Config {
+    default: None,
+    targets: vec![],
+});
+
+/// Replace the process-wide tracing configuration.
+pub fn configure(config: Config) {
+    ACTIVE.store(!config.is_silent(), Ordering::Relaxed);
+    *CONFIG.write().unwrap_or_else(|e| e.into_inner()) = config;
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    ACTIVE.load(Ordering::Relaxed)
+        && CONFIG
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .level_for(target)
+            .is_some_and(|max| level <= max)
+}
+
+#[doc(hidden)]
+pub fn emit(target: &str, level: Level, message: fmt::Arguments<'_>) {
+    eprintln!("[{} {}] {}", target, level, message);
+}
+
+/// Emit a message for `target` at `level` if tracing is enabled for it.
+/// The message is only formatted when it will actually be printed.
+///
+/// ```
+/// aoc2023::trace!("day05", Trace, "{} {} -> {}", "seed", 79, 81);
+/// ```
+#[macro_export]
+macro_rules! trace {
+    ($target:expr, $level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($target, $crate::trace::Level::$level) {
+            $crate::trace::emit($target, $crate::trace::Level::$level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[test]
+fn targets_override_the_default() {
+    let config = Config::everything(Level::Info)
+        .with_spec("day05")
+        .unwrap()
+        .with_spec("day03=debug")
+        .unwrap();
+
+    assert_eq!(Some(Level::Trace), config.level_for("day05"));
+    assert_eq!(Some(Level::Debug), config.level_for("day03"));
+    assert_eq!(Some(Level::Info), config.level_for("day01"));
+    assert_eq!(None, Config::default().level_for("day01"));
+}
+
+#[test]
+fn bad_specs_are_rejected() {
+    assert!(Config::default().with_spec("day05=loud").is_err());
+    assert!(Config::default().with_spec("=trace").is_err());
+}